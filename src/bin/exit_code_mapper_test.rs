@@ -0,0 +1,42 @@
+use narrate::{error_from, CliError, ExitCode};
+
+fn mapper(err: &CliError) -> i32 {
+    match err {
+        CliError::Config => 42,
+        _ => i32::from(err),
+    }
+}
+
+fn main() {
+    let args: Vec<_> = std::env::args().skip(1).collect();
+    let error = error_from!(CliError::Config).wrap("wrapped");
+
+    for arg in &args {
+        match arg.as_str() {
+            "-m" => {
+                if narrate::set_exit_code_mapper(mapper).is_err() {
+                    eprintln!("mapper already set");
+                }
+            }
+            // compute the wrapped error's exit code before later flags
+            "-c" => {
+                error.exit_code();
+            }
+            "-w" => {}
+            other => {
+                eprintln!(
+                    "usage: exit_code_mapper_test [-c] [-m]... [-w], got: {}",
+                    other
+                );
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let code = if args.iter().any(|arg| arg == "-w") {
+        error.exit_code()
+    } else {
+        CliError::Config.exit_code()
+    };
+    std::process::exit(code);
+}
@@ -0,0 +1,11 @@
+use narrate::{report, Color};
+
+mod util;
+
+fn main() {
+    let mut args: Vec<_> = std::env::args().collect();
+    args.retain(|arg| !util::apply_config_arg(arg));
+
+    let lines: Vec<_> = args[1..].iter().map(String::as_str).collect();
+    report::banner(&lines, Color::Cyan);
+}
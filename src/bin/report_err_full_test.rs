@@ -1,4 +1,9 @@
-use narrate::{error_from, report};
+use narrate::{
+    error_from,
+    report::{self, Severity},
+};
+
+mod util;
 
 fn main() {
     let mut args = std::env::args();
@@ -8,23 +13,69 @@ fn main() {
     let mut error = match args.next() {
         Some(msg) => error_from!(msg),
         None => {
-            eprintln!("usage: {} <error list [-h help msg]>", bin_name);
+            eprintln!(
+                "usage: {} <error list [-h help msg] [-H hint] [-T tty help] [-D default help]> [-w|-n] [--config-option]",
+                bin_name
+            );
             std::process::exit(1);
         }
     };
 
+    let mut severity = Severity::Error;
     let mut help_flag = false;
+    let mut hint_flag = false;
+    let mut tty_help_flag = false;
+    let mut default_help_flag = false;
+    let mut default_help = None;
 
     for arg in args {
+        if util::apply_config_arg(&arg) {
+            continue;
+        }
+        if arg == "-w" {
+            severity = Severity::Warning;
+            continue;
+        }
+        if arg == "-n" {
+            severity = Severity::Note;
+            continue;
+        }
         if arg == "-h" {
             help_flag = true;
             continue;
         }
+        if arg == "-H" {
+            hint_flag = true;
+            continue;
+        }
+        if arg == "-T" {
+            tty_help_flag = true;
+            continue;
+        }
+        if arg == "-D" {
+            default_help_flag = true;
+            continue;
+        }
         if help_flag {
             error.add_help_with(|| arg);
             help_flag = false;
             continue;
         }
+        if hint_flag {
+            error.add_hint(Box::leak(arg.into_boxed_str()));
+            hint_flag = false;
+            continue;
+        }
+        if tty_help_flag {
+            error.add_help_with_if_tty(|| arg);
+            tty_help_flag = false;
+            continue;
+        }
+        if default_help_flag {
+            default_help = Some(arg);
+            default_help_flag = false;
+            continue;
+        }
         error = error.wrap(arg);
     }
 
@@ -33,5 +84,29 @@ fn main() {
         std::process::exit(1);
     }
 
-    report::err_full(&error);
+    if hint_flag {
+        eprintln!("Expected hint after -H");
+        std::process::exit(1);
+    }
+
+    if tty_help_flag {
+        eprintln!("Expected help message after -T");
+        std::process::exit(1);
+    }
+
+    if default_help_flag {
+        eprintln!("Expected help message after -D");
+        std::process::exit(1);
+    }
+
+    if let Some(default_help) = default_help {
+        report::err_full_with_default_help(&error, &default_help);
+        return;
+    }
+
+    match severity {
+        Severity::Error => report::err_full(&error),
+        Severity::Warning => report::warn_full(&error),
+        severity => report::diagnostic_full(severity, &error),
+    }
 }
@@ -1,5 +1,7 @@
 use narrate::{error_from, report};
 
+mod util;
+
 fn main() {
     let mut args = std::env::args();
     let bin_name = args.next().expect("will always has executable name");
@@ -8,23 +10,49 @@ fn main() {
     let mut error = match args.next() {
         Some(msg) => error_from!(msg),
         None => {
-            eprintln!("usage: {} <error list [-h help msg]>", bin_name);
+            eprintln!(
+                "usage: {} <error list [-h help msg] [-H hint]> [-b|-a] [--config-option]",
+                bin_name
+            );
             std::process::exit(1);
         }
     };
 
+    let mut boxed = false;
+    let mut all_help = false;
     let mut help_flag = false;
+    let mut hint_flag = false;
 
     for arg in args {
+        if util::apply_config_arg(&arg) {
+            continue;
+        }
+        if arg == "-b" {
+            boxed = true;
+            continue;
+        }
+        if arg == "-a" {
+            all_help = true;
+            continue;
+        }
         if arg == "-h" {
             help_flag = true;
             continue;
         }
+        if arg == "-H" {
+            hint_flag = true;
+            continue;
+        }
         if help_flag {
             error.add_help_with(|| arg);
             help_flag = false;
             continue;
         }
+        if hint_flag {
+            error.add_hint(Box::leak(arg.into_boxed_str()));
+            hint_flag = false;
+            continue;
+        }
         error = error.wrap(arg);
     }
 
@@ -33,5 +61,14 @@ fn main() {
         std::process::exit(1);
     }
 
-    report::err(&error);
+    if hint_flag {
+        eprintln!("Expected hint after -H");
+        std::process::exit(1);
+    }
+
+    match (boxed, all_help) {
+        (true, _) => report::err_boxed(&error),
+        (false, true) => report::err_with_full_help(&error),
+        (false, false) => report::err(&error),
+    }
 }
@@ -0,0 +1,46 @@
+use narrate::{error_from, report};
+
+mod util;
+
+fn main() {
+    let mut args: Vec<_> = std::env::args().collect();
+    args.retain(|arg| !util::apply_config_arg(arg));
+    let mut args = args.into_iter();
+    let bin_name = args.next().expect("will always has executable name");
+
+    match args.next().as_deref() {
+        Some("warn") => match args.next() {
+            Some(msg) => report::warn_github(msg),
+            None => usage(&bin_name),
+        },
+        Some("err") => {
+            let mut error = match args.next() {
+                Some(msg) => error_from!(msg),
+                None => usage(&bin_name),
+            };
+            let mut help_flag = false;
+            for arg in args {
+                if arg == "-h" {
+                    help_flag = true;
+                    continue;
+                }
+                if help_flag {
+                    error.add_help_with(|| arg);
+                    help_flag = false;
+                    continue;
+                }
+                error = error.wrap(arg);
+            }
+            report::err_github(&error);
+        }
+        _ => usage(&bin_name),
+    }
+}
+
+fn usage(bin_name: &str) -> ! {
+    eprintln!(
+        "usage: {} [--config-option] <err <error list [-h help msg]> | warn <msg>>",
+        bin_name
+    );
+    std::process::exit(1);
+}
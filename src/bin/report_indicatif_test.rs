@@ -0,0 +1,17 @@
+use indicatif::{MultiProgress, ProgressDrawTarget};
+use narrate::{error_from, report, Color};
+
+fn main() {
+    let progress = MultiProgress::with_draw_target(ProgressDrawTarget::hidden());
+    let mut args = std::env::args().skip(1);
+    match (args.next().as_deref(), args.next()) {
+        (Some("status"), Some(msg)) => {
+            report::status_via(&progress, "Compiling", msg, Color::Green)
+        }
+        (Some("err"), Some(msg)) => report::err_via(&progress, &error_from!(msg)),
+        _ => {
+            eprintln!("usage: report_indicatif_test <status|err> <msg>");
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use std::time::{Duration, Instant};
+
+use narrate::{report, Color};
+
+mod util;
+
+fn main() {
+    let mut args: Vec<_> = std::env::args().collect();
+    args.retain(|arg| !util::apply_config_arg(arg));
+
+    let start = match args.get(1).is_some_and(|arg| arg == "-e") && args.len() > 2 {
+        true => {
+            let secs = args.drain(1..3).nth(1).expect("seconds argument");
+            let secs = secs.parse().expect("seconds is a number");
+            Some(Instant::now() - Duration::from_secs(secs))
+        }
+        false => None,
+    };
+
+    if args.len() < 4 {
+        eprintln!(
+            "usage: {} [--config-option] [-e <secs ago>] <title> <total> <done>...",
+            args[0]
+        );
+        std::process::exit(1);
+    }
+
+    let total = args[2].parse().expect("total is a number");
+    for done in &args[3..] {
+        let done = done.parse().expect("done is a number");
+        match start {
+            Some(start) => report::status_percent_eta(&args[1], done, total, start, Color::Cyan),
+            None => report::status_percent(&args[1], done, total, Color::Cyan),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::str::FromStr;
+
+use narrate::{
+    report::{self, Severity},
+    Color,
+};
+
+mod util;
+
+fn main() {
+    let mut args: Vec<_> = std::env::args().collect();
+    args.retain(|arg| !util::apply_config_arg(arg));
+
+    if args.len() != 4 && args.len() != 5 {
+        eprintln!(
+            "usage: {} [--config-option] <error|warning|note|info|help> <title> <msg> [color]",
+            args[0]
+        );
+        std::process::exit(1);
+    }
+
+    let severity = match args[1].as_str() {
+        "error" => Severity::Error,
+        "warning" => Severity::Warning,
+        "note" => Severity::Note,
+        "info" => Severity::Info,
+        "help" => Severity::Help,
+        other => {
+            eprintln!("error: not a valid severity: {}", other);
+            std::process::exit(1);
+        }
+    };
+
+    let (title, msg) = (&args[2], &args[3]);
+    match args.get(4) {
+        Some(color) => {
+            let color = Color::from_str(color).unwrap_or_else(|_| {
+                eprintln!("error: not a valid color: {}", color);
+                std::process::exit(1);
+            });
+            report::report_colored(severity, title, msg, color);
+        }
+        None => report::report(severity, title, msg),
+    }
+}
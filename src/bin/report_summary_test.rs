@@ -0,0 +1,31 @@
+use narrate::{anyhow::anyhow, report};
+
+fn main() {
+    let mut args: Vec<_> = std::env::args().collect();
+    let bin_name = args.remove(0);
+
+    // with a guard, the summary is printed on drop instead
+    let guard = match args.first().is_some_and(|arg| arg == "-g") {
+        true => Some(report::summary_guard()),
+        false => None,
+    };
+
+    for arg in &args[usize::from(guard.is_some())..] {
+        match arg.as_str() {
+            "err" => report::anyhow_err(&anyhow!("error")),
+            "warn" => report::warn("warning"),
+            "note" => report::note("note"),
+            "info" => report::info("info"),
+            "reset" => report::reset_counts(),
+            other => {
+                eprintln!("usage: {} [-g] <err|warn|note|info|reset>...", bin_name);
+                eprintln!("error: not a valid report: {}", other);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if guard.is_none() {
+        report::summary_counts();
+    }
+}
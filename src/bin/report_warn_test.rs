@@ -0,0 +1,33 @@
+use narrate::report::{self, Severity};
+
+mod util;
+
+fn main() {
+    let mut args: Vec<_> = std::env::args().collect();
+    args.retain(|arg| !util::apply_config_arg(arg));
+    if args.len() == 2 && args[1] == "clear" {
+        report::clear_line();
+        return;
+    }
+
+    if args.len() != 3 {
+        eprintln!(
+            "usage: {} <warn|note|info|help|error> <msg> | clear",
+            args[0]
+        );
+        std::process::exit(1);
+    }
+
+    let msg = &args[2];
+    match args[1].as_str() {
+        "warn" => report::warn(msg),
+        "note" => report::note(msg),
+        "info" => report::info(msg),
+        "help" => report::diagnostic(Severity::Help, msg),
+        "error" => report::diagnostic(Severity::Error, msg),
+        other => {
+            eprintln!("error: not a valid report function: {}", other);
+            std::process::exit(1);
+        }
+    }
+}
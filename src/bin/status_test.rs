@@ -1,16 +1,77 @@
 use std::str::FromStr;
 
-use narrate::{report, Color};
+use narrate::{
+    report::{self, Stream},
+    Color,
+};
+
+mod util;
 
 fn main() {
-    let args: Vec<_> = std::env::args().collect();
+    let mut args: Vec<_> = std::env::args().collect();
+    args.retain(|arg| !util::apply_config_arg(arg));
+
+    if args.len() == 3 {
+        match args[1].as_str() {
+            "-s" => return report::status_skipped(&args[2]),
+            "-D" => return report::status_done(&args[2]),
+            "-F" => return report::status_failed(&args[2]),
+            _ => {}
+        }
+    }
+
+    let stdout = args.get(1).is_some_and(|arg| arg == "-o");
+    if stdout {
+        args.remove(1);
+    }
+
+    let count = args.get(1).is_some_and(|arg| arg == "-c");
+    if count {
+        args.remove(1);
+    }
+
+    let dedup = args.get(1).is_some_and(|arg| arg == "-d");
+    if dedup {
+        args.remove(1);
+    }
+
+    let annotation = match args.get(1).is_some_and(|arg| arg == "-a") && args.len() > 2 {
+        true => Some(args.drain(1..3).nth(1).expect("annotation argument")),
+        false => None,
+    };
+
+    let indent = match args.get(1).is_some_and(|arg| arg == "-i") && args.len() > 2 {
+        true => Some(args.drain(1..3).nth(1).expect("indent argument")),
+        false => None,
+    };
+
+    let stream = match args.get(1).is_some_and(|arg| arg == "-t") && args.len() > 2 {
+        true => Some(args.drain(1..3).nth(1).expect("stream argument")),
+        false => None,
+    };
+
+    let glyph = match args.get(1).is_some_and(|arg| arg == "-g") && args.len() > 2 {
+        true => Some(args.drain(1..3).nth(1).expect("glyph argument")),
+        false => None,
+    };
+
     if args.len() != 4 {
-        eprintln!("usage: {} <title> <msg> <color>", args[0]);
+        eprintln!(
+            "usage: {} [--config-option] [-o | -c | -d | -i <indent> | -t <stdout|stderr> | -g <glyph> | -a <annotation>] <title> <msg|count noun|msg,msg...> <color|#rrggbb> | -s|-D|-F <msg>",
+            args[0]
+        );
         std::process::exit(1);
     }
 
     let title = &args[1];
     let msg = &args[2];
+
+    if let Some(hex) = args[3].strip_prefix('#') {
+        let rgb = u32::from_str_radix(hex, 16).expect("hex color");
+        let [_, r, g, b] = rgb.to_be_bytes();
+        report::status_rgb(title, msg, r, g, b);
+        return;
+    }
     let color = match Color::from_str(&args[3]) {
         Ok(color) => color,
         Err(err) => {
@@ -19,5 +80,34 @@ fn main() {
         }
     };
 
-    report::status(title, msg, color);
+    if stdout {
+        report::status_stdout(title, msg, color);
+    } else if let Some(stream) = stream {
+        let stream = match stream.as_str() {
+            "stdout" => Stream::Stdout,
+            "stderr" => Stream::Stderr,
+            other => {
+                eprintln!("error: not a valid stream: {}", other);
+                std::process::exit(1);
+            }
+        };
+        report::status_stream(stream, title, msg, color);
+    } else if let Some(indent) = indent {
+        let indent = indent.parse().expect("indent is a number");
+        report::status_indent(indent, title, msg, color);
+    } else if let Some(annotation) = annotation {
+        report::status_annotated(title, msg, &annotation, color);
+    } else if let Some(glyph) = glyph {
+        report::status_glyph(&glyph, title, msg, color);
+    } else if dedup {
+        for msg in msg.split(',') {
+            report::status_dedup(title, msg, color);
+        }
+    } else if count {
+        let (count, noun) = msg.split_once(' ').expect("count and noun");
+        let count = count.parse().expect("count is a number");
+        report::status_count(title, count, noun, color);
+    } else {
+        report::status(title, msg, color);
+    }
 }
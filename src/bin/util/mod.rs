@@ -0,0 +1,87 @@
+use narrate::report;
+
+/// Apply a `--<option>[=<value>]` argument to the report configuration.
+///
+/// Returns `false` if the argument is not a config option.
+pub fn apply_config_arg(arg: &str) -> bool {
+    let Some(option) = arg.strip_prefix("--") else {
+        return false;
+    };
+    let (name, value) = match option.split_once('=') {
+        Some((name, value)) => (name, Some(value)),
+        None => (option, None),
+    };
+
+    if (name, value) == ("section", None) {
+        // keep the section open until the process exits
+        std::mem::forget(report::section());
+        return true;
+    }
+
+    if (name, value) == ("stdout", None) {
+        report::with_writer(std::io::stdout);
+        return true;
+    }
+
+    if let ("color", Some(choice)) = (name, value) {
+        report::set_color_choice(match choice {
+            "always" => report::ColorChoice::Always,
+            "never" => report::ColorChoice::Never,
+            _ => report::ColorChoice::Auto,
+        });
+        return true;
+    }
+
+    if let ("tee", Some(path)) = (name, value) {
+        let file = std::fs::File::create(path).unwrap_or_else(|err| {
+            eprintln!("error: cannot create tee file: {}", err);
+            std::process::exit(1);
+        });
+        report::set_tee(file);
+        return true;
+    }
+
+    let mut config = report::config();
+    match (name, value) {
+        ("trailing-newline", None) => config.trailing_newline = true,
+        ("cause-label", Some(label)) => config.cause_label = leak(label),
+        ("collapse-single-cause", None) => config.collapse_single_cause = true,
+        ("show-duplicate-cause", None) => config.hide_duplicate_cause = false,
+        ("count-width", Some(width)) => config.count_width = parse(arg, width),
+        ("redact-digits", None) => config.redactor = Some(redact_digits),
+        ("indent-width", Some(width)) => config.indent_width = parse(arg, width),
+        ("ascii", None) => config.ascii = true,
+        #[cfg(feature = "icons")]
+        ("icons", None) => config.icons = true,
+        ("max-causes", Some(max)) => config.max_causes = Some(parse(arg, max)),
+        ("justify", Some("leading")) => config.justify = report::Justify::Leading,
+        ("justify", Some("trailing")) => config.justify = report::Justify::Trailing,
+        ("cause-color", Some(color)) => config.style.cause_message = Some(parse(arg, color)),
+        ("error-title", Some(word)) => config.theme.error.word = leak(word),
+        ("warning-title", Some(word)) => config.theme.warning.word = leak(word),
+        ("note-color", Some(color)) => config.theme.note.color = parse(arg, color),
+        _ => {
+            eprintln!("error: not a valid config option: {}", arg);
+            std::process::exit(1);
+        }
+    }
+    report::set_config(config);
+    true
+}
+
+fn leak(value: &str) -> &'static str {
+    Box::leak(value.to_owned().into_boxed_str())
+}
+
+fn parse<T: std::str::FromStr>(arg: &str, value: &str) -> T {
+    value.parse().unwrap_or_else(|_| {
+        eprintln!("error: not a valid config value: {}", arg);
+        std::process::exit(1);
+    })
+}
+
+fn redact_digits(msg: &str) -> String {
+    msg.chars()
+        .map(|c| if c.is_ascii_digit() { '*' } else { c })
+        .collect()
+}
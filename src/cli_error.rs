@@ -1,21 +1,108 @@
-use std::fmt;
+use std::{fmt, io::ErrorKind, path::PathBuf, sync::OnceLock};
 
 use crate::{CliError, ExitCode};
 
+static EXIT_CODE_MAPPER: OnceLock<fn(&CliError) -> i32> = OnceLock::new();
+
+/// Override the exit codes of [`CliError`]s for the rest of the program.
+///
+/// Once set, [`ExitCode::exit_code`] returns `mapper(err)` for every
+/// [`CliError`], including those found in the chain of an
+/// [`Error`](crate::Error). Without a mapper the
+/// [sysexits.h](https://man.openbsd.org/sysexits) codes are
+/// used. These are still available as `i32::from(err)`, so a mapper can fall
+/// back to them.
+///
+/// The mapper can only be set once. If one is already set, `mapper` is
+/// returned in the `Err`. Exit codes computed before the mapper was set are
+/// not kept, so an [`Error`](crate::Error) created earlier still uses it.
+///
+/// # Example
+///
+/// ```
+/// use narrate::{CliError, ExitCode};
+///
+/// narrate::set_exit_code_mapper(|err| match err {
+///     CliError::Config => 2,
+///     _ => i32::from(err),
+/// })
+/// .unwrap();
+///
+/// assert_eq!(2, CliError::Config.exit_code());
+/// assert_eq!(exitcode::USAGE, CliError::Usage.exit_code());
+/// ```
+pub fn set_exit_code_mapper(mapper: fn(&CliError) -> i32) -> Result<(), fn(&CliError) -> i32> {
+    EXIT_CODE_MAPPER.set(mapper)
+}
+
+#[cfg(feature = "error")]
+pub(crate) fn has_exit_code_mapper() -> bool {
+    EXIT_CODE_MAPPER.get().is_some()
+}
+
+impl CliError {
+    /// Create the most specific error for an I/O failure on `path`.
+    ///
+    /// | [`ErrorKind`]      | Variant                                                |
+    /// |--------------------|--------------------------------------------------------|
+    /// | `NotFound`         | [`InputFileNotFound`](CliError::InputFileNotFound)     |
+    /// | `PermissionDenied` | [`OperationPermission`](CliError::OperationPermission) |
+    /// | `AlreadyExists`    | [`AlreadyExists`](CliError::AlreadyExists)             |
+    /// | any other kind     | [`Io`](CliError::Io), keeping the kind                 |
+    ///
+    /// Pairs with [`ErrorWrap::wrap_io`](crate::ErrorWrap::wrap_io), so the
+    /// original [`io::Error`](std::io::Error) is kept as the cause.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::{fs, path::PathBuf};
+    ///
+    /// use narrate::{CliError, ErrorWrap, ExitCode, Result};
+    ///
+    /// fn read_config(path: PathBuf) -> Result<String> {
+    ///     fs::read_to_string(&path).wrap_io(|kind| CliError::from_io(kind, path))
+    /// }
+    ///
+    /// let err = read_config(PathBuf::from("/does/not/exist.toml")).unwrap_err();
+    /// assert_eq!("file not found: /does/not/exist.toml", err.to_string());
+    /// assert_eq!(exitcode::NOINPUT, err.exit_code());
+    /// ```
+    pub fn from_io(kind: ErrorKind, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        match kind {
+            ErrorKind::NotFound => CliError::InputFileNotFound(path),
+            ErrorKind::PermissionDenied => {
+                CliError::OperationPermission(path.display().to_string())
+            }
+            ErrorKind::AlreadyExists => CliError::AlreadyExists(path),
+            kind => CliError::Io { kind, path },
+        }
+    }
+}
+
 impl std::error::Error for CliError {}
 
 impl fmt::Display for CliError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use CliError::*;
         match self {
+            AlreadyExists(path) => write!(f, "already exists: {}", path.display()),
+
             Config => write!(f, "invalid configuration"),
 
+            Conflict(resource) => write!(f, "conflict: {}", resource),
+
             CreateFile(file) => write!(f, "cannot create file: {}", file.display()),
 
+            Custom { message, .. } => write!(f, "{}", message),
+
             InputData => write!(f, "invalid input data"),
 
             InputFileNotFound(file) => write!(f, "file not found: {}", file.display()),
 
+            Io { kind, path } => write!(f, "{}: {}", kind, path.display()),
+
             NoUser(user) => write!(f, "user not found: {}", user),
 
             NoHost(host) => write!(f, "host not found: {}", host),
@@ -26,6 +113,14 @@ impl fmt::Display for CliError {
 
             OsFileNotFound(file) => write!(f, "system file not found: {}", file.display()),
 
+            PartialFailure { succeeded, failed } => write!(
+                f,
+                "completed with {} failure{} ({} succeeded)",
+                failed,
+                if *failed == 1 { "" } else { "s" },
+                succeeded
+            ),
+
             ReadFile(file) => write!(f, "cannot read file: {}", file.display()),
 
             ResourceNotFound(resource) => write!(f, "resource not found: {}", resource),
@@ -34,6 +129,8 @@ impl fmt::Display for CliError {
 
             Temporary => write!(f, "temporary failure"),
 
+            Timeout(elapsed) => write!(f, "operation timed out after {:?}", elapsed),
+
             Usage => write!(f, "incorrect usage"),
 
             WriteFile(file) => write!(f, "cannot write to file: {}", file.display()),
@@ -43,7 +140,10 @@ impl fmt::Display for CliError {
 
 impl ExitCode for CliError {
     fn exit_code(&self) -> i32 {
-        self.into()
+        match EXIT_CODE_MAPPER.get() {
+            Some(mapper) => mapper(self),
+            None => self.into(),
+        }
     }
 }
 
@@ -59,18 +159,157 @@ impl From<&CliError> for i32 {
         use CliError::*;
         match err {
             Config => CONFIG,
-            CreateFile(_) => CANTCREAT,
+            AlreadyExists(_) | Conflict(_) | CreateFile(_) => CANTCREAT,
+            Custom { code, .. } => *code,
             InputData | ResourceNotFound(_) => DATAERR,
             InputFileNotFound(_) => NOINPUT,
+            Io { kind, .. } => match kind {
+                ErrorKind::NotFound => NOINPUT,
+                ErrorKind::PermissionDenied => NOPERM,
+                ErrorKind::AlreadyExists => CANTCREAT,
+                ErrorKind::Interrupted | ErrorKind::TimedOut | ErrorKind::WouldBlock => TEMPFAIL,
+                _ => IOERR,
+            },
             NoUser(_) => NOUSER,
             NoHost(_) => NOHOST,
             OperationPermission(_) => NOPERM,
             OsErr => OSERR,
             OsFileNotFound(_) => OSFILE,
+            PartialFailure { .. } => DATAERR,
             ReadFile(_) | WriteFile(_) => IOERR,
             Protocol => PROTOCOL,
-            Temporary => TEMPFAIL,
+            Temporary | Timeout(_) => TEMPFAIL,
             Usage => USAGE,
         }
     }
 }
+
+/// Define an error enum with its own exit codes, like [`CliError`].
+///
+/// Each variant is declared as `Variant => (code, "message")`. The macro
+/// generates:
+///
+/// - the enum, deriving [`Debug`], [`Clone`], [`Copy`], [`PartialEq`], [`Eq`]
+///   and [`Hash`].
+/// - [`Display`](std::fmt::Display), printing the message.
+/// - an `exit_code` method and `From<&Enum> for i32`, returning the code.
+/// - `into_error`, converting into a [`narrate::Error`](crate::Error) that
+///   keeps the code.
+///
+/// Requires both the `cli-error` and `error` features.
+///
+/// The enum does not implement [`std::error::Error`], so it cannot be
+/// converted with `?` or [`Error::new`](crate::Error::new), which would lose
+/// the code. Use `into_error` instead:
+///
+/// ```compile_fail
+/// use narrate::{define_cli_error, Result};
+///
+/// define_cli_error! {
+///     pub enum DeployError {
+///         Locked => (75, "environment is locked"),
+///     }
+/// }
+///
+/// fn deploy() -> Result<()> {
+///     Err(DeployError::Locked)?
+/// }
+/// ```
+///
+/// For one or two app-specific errors, [`CliError::Custom`] gives a message
+/// and exit code without defining a new type:
+///
+/// ```
+/// use narrate::{CliError, Error, ExitCode};
+///
+/// let error = Error::new(CliError::Custom {
+///     code: 3,
+///     message: String::from("deployment rejected"),
+/// });
+/// assert_eq!("deployment rejected", error.to_string());
+/// assert_eq!(3, error.exit_code());
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{define_cli_error, ExitCode, Result};
+///
+/// define_cli_error! {
+///     /// Errors for a deployment tool.
+///     pub enum DeployError {
+///         /// Target environment is locked
+///         Locked => (75, "environment is locked"),
+///         /// Health check failed after deploying
+///         Unhealthy => (80, "deployment is unhealthy"),
+///     }
+/// }
+///
+/// fn deploy() -> Result<()> {
+///     Err(DeployError::Locked.into_error())
+/// }
+///
+/// let err = deploy().unwrap_err();
+/// assert_eq!("environment is locked", err.to_string());
+/// assert_eq!(75, err.exit_code());
+/// ```
+#[cfg(all(feature = "cli-error", feature = "error"))]
+#[macro_export]
+macro_rules! define_cli_error {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => ($code:expr, $msg:literal)
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// Convert into a `narrate::Error` that keeps this exit code.
+            #[allow(dead_code)]
+            #[track_caller]
+            $vis fn into_error(self) -> $crate::Error {
+                let code = self.exit_code();
+                $crate::Error::msg(self).with_exit_code(code)
+            }
+
+            /// CLI application exit code
+            #[allow(dead_code)]
+            $vis fn exit_code(&self) -> i32 {
+                self.into()
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    $(Self::$variant => f.write_str($msg),)*
+                }
+            }
+        }
+
+        impl ::core::convert::From<&$name> for i32 {
+            fn from(err: &$name) -> Self {
+                match err {
+                    $($name::$variant => $code,)*
+                }
+            }
+        }
+
+        impl ::core::convert::From<$name> for i32 {
+            fn from(err: $name) -> Self {
+                (&err).into()
+            }
+        }
+    };
+}
@@ -79,3 +79,147 @@ macro_rules! bail {
         return ::core::result::Result::Err($crate::error_from!($fmt, $($arg)*))
     };
 }
+
+/// Return early with an error and a help message.
+///
+/// The error and the help are separated by a `;`. The error accepts the same
+/// arguments as [`error_from!`]: a literal, an expression, or a format string
+/// and its arguments. The help is a format string and its arguments, so like
+/// the error it can capture variables inline, such as `"check {path}"`. It is
+/// added with [`add_help_with`](crate::Error::add_help_with).
+///
+/// The surrounding function's or closure's return value is required to be
+/// `Result<_,`[`narrate::Error`][crate::Error]`>`.
+///
+/// [error_from!]: crate::error_from
+///
+/// # Example
+///
+/// ```
+/// # use narrate::{bail_help, Result};
+/// #
+/// fn open(name: &str) -> Result<()> {
+///     if name.is_empty() {
+///         bail_help!("no file name given"; "pass a file name as the first argument");
+///     }
+///     bail_help!("cannot open {}", name; "check that {} exists", name)
+/// }
+///
+/// let err = open("").unwrap_err();
+/// assert_eq!(Some("pass a file name as the first argument"), err.help());
+///
+/// let err = open("data.csv").unwrap_err();
+/// assert_eq!("cannot open data.csv", err.to_string());
+/// assert_eq!(Some("check that data.csv exists"), err.help());
+/// ```
+#[macro_export]
+macro_rules! bail_help {
+    (@help $err:expr; $($help:tt)+) => {{
+        let mut error = $err;
+        error.add_help_with(|| ::std::format!($($help)+));
+        return ::core::result::Result::Err(error);
+    }};
+    ($msg:literal; $($help:tt)+) => {
+        $crate::bail_help!(@help $crate::error_from!($msg); $($help)+)
+    };
+    ($fmt:literal, $($arg:expr),+; $($help:tt)+) => {
+        $crate::bail_help!(@help $crate::error_from!($fmt, $($arg),+); $($help)+)
+    };
+    ($err:expr; $($help:tt)+) => {
+        $crate::bail_help!(@help $crate::error_from!($err); $($help)+)
+    };
+}
+
+/// Return early with an error if a condition is not satisfied.
+///
+/// This macro is equivalent to
+/// `if !(cond) { return Err(`[`error_from!($args...)`][error_from!]`); }`.
+///
+/// The surrounding function's or closure's return value is required to be
+/// `Result<_,`[`narrate::Error`][crate::Error]`>`.
+///
+/// [error_from!]: crate::error_from
+///
+/// # Example
+///
+/// ```
+/// # use narrate::{ensure, Result};
+/// #
+/// fn set_threads(count: usize) -> Result<()> {
+///     ensure!(count > 0, "thread count must be positive");
+///     ensure!(count <= 64, "too many threads: {}", count);
+///     Ok(())
+/// }
+///
+/// assert!(set_threads(8).is_ok());
+/// assert_eq!("too many threads: 100", set_threads(100).unwrap_err().to_string());
+/// ```
+#[macro_export]
+macro_rules! ensure {
+    ($cond:expr, $msg:literal $(,)?) => {
+        if !$cond {
+            return ::core::result::Result::Err($crate::error_from!($msg));
+        }
+    };
+    ($cond:expr, $err:expr $(,)?) => {
+        if !$cond {
+            return ::core::result::Result::Err($crate::error_from!($err));
+        }
+    };
+    ($cond:expr, $fmt:expr, $($arg:tt)*) => {
+        if !$cond {
+            return ::core::result::Result::Err($crate::error_from!($fmt, $($arg)*));
+        }
+    };
+}
+
+/// Lazily wrap a `Result`'s error with formatted context.
+///
+/// This macro is equivalent to
+/// `result.`[`wrap_with`][crate::ErrorWrap::wrap_with]`(|| format!($args...))`.
+///
+/// Passing `format!(...)` straight to [`wrap`][crate::ErrorWrap::wrap]
+/// allocates the context string even when the `Result` is `Ok`. Using this
+/// macro only formats the context if there is an error to wrap.
+///
+/// # Example
+///
+/// ```
+/// use narrate::{wrap, Result};
+///
+/// fn read_config(path: &str) -> Result<String> {
+///     let config = wrap!(std::fs::read_to_string(path), "cannot read config: {}", path)?;
+///     Ok(config)
+/// }
+/// #
+/// # assert!(read_config("/does/not/exist").is_err());
+/// ```
+#[macro_export]
+macro_rules! wrap {
+    ($result:expr, $($arg:tt)+) => {
+        $crate::ErrorWrap::wrap_with($result, || ::std::format!($($arg)+))
+    };
+}
+
+/// Check whether an error is any of several types.
+///
+/// This macro is equivalent to calling [`is`][crate::Error::is] once for each
+/// type and combining the results with `||`. Like `is`, each type is checked
+/// against the error and any context it has been wrapped with.
+///
+/// # Example
+///
+/// ```
+/// use narrate::{is_any_of, CliError, Error};
+///
+/// let error = Error::from(CliError::Temporary);
+/// assert!(is_any_of!(error, std::io::Error, CliError));
+/// assert!(!is_any_of!(error, std::io::Error, std::fmt::Error));
+/// ```
+#[macro_export]
+macro_rules! is_any_of {
+    ($err:expr, $($ty:ty),+ $(,)?) => {{
+        let err: &$crate::Error = &$err;
+        false $(|| err.is::<$ty>())+
+    }};
+}
@@ -1,9 +1,20 @@
-use std::{error::Error as StdError, fmt};
+use std::{
+    any::Any,
+    backtrace::{Backtrace, BacktraceStatus},
+    borrow::Cow,
+    error::Error as StdError,
+    fmt,
+    panic::Location,
+    process::ExitStatus,
+    time::Duration,
+};
 
-use crate::{Chain, Error};
+use crate::{Chain, Error, ErrorElement};
 
 mod chain;
 mod macros;
+#[cfg(feature = "serde")]
+mod serialize;
 pub(crate) mod wrap;
 
 impl Error {
@@ -11,14 +22,12 @@ impl Error {
     ///
     /// The error type must be thread-safe and `'static`, so that the `Error`
     /// will be as well.
+    #[track_caller]
     pub fn new<E>(error: E) -> Self
     where
         E: StdError + Send + Sync + 'static,
     {
-        Self {
-            inner: error.into(),
-            help: None,
-        }
+        Self::from_anyhow(error.into()).with_type_name::<E>()
     }
 
     /// Create a new error object from a printable error message.
@@ -50,14 +59,12 @@ impl Error {
     /// }
     /// # */
     /// ```
+    #[track_caller]
     pub fn msg<M>(message: M) -> Self
     where
         M: fmt::Display + fmt::Debug + Send + Sync + 'static,
     {
-        Self {
-            inner: anyhow::Error::msg(message),
-            help: None,
-        }
+        Self::from_anyhow(anyhow::Error::msg(message)).with_type_name::<M>()
     }
 
     /// Convert an [`anyhow::Error`] into an error object.
@@ -65,10 +72,111 @@ impl Error {
     /// Due to the generic implementation of [`From`] for [`Error`]: we cannot
     /// add a `From<anyhow::Error>` impl. Use this instead.
     #[inline]
+    #[track_caller]
     pub fn from_anyhow(error: anyhow::Error) -> Self {
         Self {
             inner: error,
             help: None,
+            hint: None,
+            extras: Some(Box::new(Extras {
+                location: Some(Location::caller()),
+                ..Default::default()
+            })),
+            #[cfg(feature = "cli-error")]
+            exit_code: None,
+            #[cfg(feature = "cli-error")]
+            exit_code_cache: std::sync::OnceLock::new(),
+        }
+    }
+
+    #[inline]
+    fn with_type_name<E>(mut self) -> Self {
+        self.extras_mut().type_name = Some(std::any::type_name::<E>());
+        self
+    }
+
+    /// Create an error for a child process that did not succeed.
+    ///
+    /// The message names the command `cmd` and the child's exit code, such as
+    /// `command 'git' failed with exit code 128`. With the `cli-error`
+    /// feature, the child's exit code is also returned by
+    /// [`exit_code`](crate::ExitCode::exit_code), so it can be passed on. If
+    /// the child was terminated by a signal there is no exit code to pass on.
+    ///
+    /// ```no_run
+    /// use std::process::Command;
+    ///
+    /// use narrate::{Error, Result};
+    ///
+    /// fn pull() -> Result<()> {
+    ///     let status = Command::new("git").arg("pull").status()?;
+    ///     if !status.success() {
+    ///         return Err(Error::from_exit_status(status, "git"));
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn from_exit_status(status: ExitStatus, cmd: &str) -> Self {
+        match status.code() {
+            Some(code) => {
+                let msg = format!("command '{}' failed with exit code {}", cmd, code);
+                #[allow(unused_mut)]
+                let mut error = Self::msg(msg);
+                #[cfg(feature = "cli-error")]
+                {
+                    error.exit_code = Some(code);
+                }
+                error
+            }
+            None => Self::msg(format!("command '{}' was terminated by a signal", cmd)),
+        }
+    }
+
+    /// Create an error that only carries an exit code.
+    ///
+    /// The message is `process exited with code N`, and `code` is returned by
+    /// [`exit_code`](crate::ExitCode::exit_code). Use
+    /// [`from_exit_status`](Error::from_exit_status) instead if you have the
+    /// [`ExitStatus`] of a child process.
+    ///
+    /// ```
+    /// use narrate::{Error, ExitCode};
+    ///
+    /// let error = Error::from_code(3);
+    /// assert_eq!("process exited with code 3", error.to_string());
+    /// assert_eq!(3, error.exit_code());
+    /// ```
+    #[cfg(feature = "cli-error")]
+    #[track_caller]
+    pub fn from_code(code: i32) -> Self {
+        let mut error = Self::msg(format!("process exited with code {}", code));
+        error.exit_code = Some(code);
+        error
+    }
+
+    /// Convert into an [`anyhow::Error`], keeping the help message.
+    ///
+    /// Converting with [`From`] drops any help message. This method attaches
+    /// the help as the outermost context instead, so the help becomes the
+    /// top-level message of the returned error, followed by this error's
+    /// chain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let mut error = error_from!("invalid configuration");
+    /// error.add_help("try `app init`");
+    /// let any_err = error.into_anyhow_keep_help();
+    /// assert_eq!("try `app init`", any_err.to_string());
+    /// assert_eq!("invalid configuration", any_err.root_cause().to_string());
+    /// ```
+    pub fn into_anyhow_keep_help(self) -> anyhow::Error {
+        match self.help {
+            Some(help) => self.inner.context(help),
+            None => self.inner,
         }
     }
 
@@ -77,6 +185,12 @@ impl Error {
     /// For attaching context to a `Result` as it is propagated, the
     /// [`ErrorWrap`](crate::ErrorWrap) extension trait may be more convenient than this function.
     ///
+    /// Wrapping with another [`Error`] only keeps its message. Use
+    /// [`wrap_error`](Error::wrap_error) to keep its causes and help as well.
+    ///
+    /// A `&'static str` context is stored as it is, without formatting or
+    /// copying it into a new message.
+    ///
     /// The primary reason to use `error.warp(...)` instead of
     /// `result.warp(...)` via the `ErrorWrap` trait would be if the context
     /// needs to depend on some data held by the underlying error:
@@ -131,8 +245,143 @@ impl Error {
     {
         Self {
             inner: self.inner.context(context),
-            help: self.help,
+            #[cfg(feature = "cli-error")]
+            exit_code_cache: std::sync::OnceLock::new(),
+            ..self
+        }
+    }
+
+    /// Wrap the error value with context that starts a new group of causes.
+    ///
+    /// Use tags to show which subsystem each part of a long chain comes
+    /// from. The context, and any further context added by
+    /// [`wrap`](Error::wrap), belongs to the `tag` group until another tag is
+    /// added. [`err_full`](crate::report::err_full) prints a `[tag]` header
+    /// above the causes of each group.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::{error_from, report};
+    ///
+    /// let error = error_from!("connection reset")
+    ///     .wrap_tagged("cannot download index", "Network")
+    ///     .wrap_tagged("index is incomplete", "Parser")
+    ///     .wrap("cannot update registry");
+    ///
+    /// let mut out = String::new();
+    /// report::render_err_to_fmt(&error, &mut out).unwrap();
+    /// assert_eq!(
+    ///     "error: cannot update registry\n\
+    ///      [Parser]\n\
+    ///      cause: index is incomplete\n\
+    ///      [Network]\n\
+    ///      cause: cannot download index\n\
+    ///      cause: connection reset\n",
+    ///     out
+    /// );
+    /// ```
+    pub fn wrap_tagged<C>(self, context: C, tag: &'static str) -> Self
+    where
+        C: fmt::Display + Send + Sync + 'static,
+    {
+        let mut error = self.wrap(context);
+        let position = error.inner.chain().len() - 1;
+        error.extras_mut().tags.push((position, tag));
+        error
+    }
+
+    /// Wrap the error value with several layers of context, in order.
+    ///
+    /// Each context is applied as a successive [`wrap`](Error::wrap), so the
+    /// last context becomes the top-level error message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let error = error_from!("connection refused").wrap_all(["cannot fetch", "update failed"]);
+    /// let chain: Vec<_> = error.chain().map(|cause| cause.to_string()).collect();
+    /// assert_eq!(vec!["update failed", "cannot fetch", "connection refused"], chain);
+    /// ```
+    pub fn wrap_all<I, C>(self, contexts: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: fmt::Display + Send + Sync + 'static,
+    {
+        contexts.into_iter().fold(self, Self::wrap)
+    }
+
+    /// Wrap the error value with another [`Error`], keeping all of its
+    /// information.
+    ///
+    /// Wrapping with [`wrap`](Error::wrap) only keeps the other error's
+    /// message. Instead, this method adds each message in `other`'s chain as
+    /// context, so `other`'s message becomes the top-level error message,
+    /// followed by its causes, then this error's chain.
+    ///
+    /// `other`'s help and hint are appended after this error's, as the
+    /// outermost error's help should be most visible. `other`'s retry delay and
+    /// [attached value](Error::with_any) are used if it has them, and its
+    /// [tags](Error::wrap_tagged) are kept on its part of the chain. Like any
+    /// other context, `other`'s causes below its first tag join this error's
+    /// last group. With the
+    /// `cli-error` feature, `other`'s [`exit_code`](crate::ExitCode::exit_code)
+    /// is kept too, including one from a [`CliError`](crate::CliError) in its
+    /// chain, unless it is [`exitcode::SOFTWARE`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let mut other = error_from!("lock held by pid 42").wrap("cannot write cache");
+    /// other.add_help("remove the stale lock file");
+    ///
+    /// let error = error_from!("disk full").wrap_error(other);
+    /// let chain: Vec<_> = error.chain().map(|cause| cause.to_string()).collect();
+    /// assert_eq!(
+    ///     vec!["cannot write cache", "lock held by pid 42", "disk full"],
+    ///     chain
+    /// );
+    /// assert_eq!(Some("remove the stale lock file"), error.help());
+    /// ```
+    pub fn wrap_error(self, other: Error) -> Self {
+        #[cfg(feature = "cli-error")]
+        let exit_code = match crate::ExitCode::exit_code(&other) {
+            code if other.exit_code.is_some() || code != exitcode::SOFTWARE => Some(code),
+            _ => None,
+        };
+        let offset = self.inner.chain().len();
+        let contexts: Vec<_> = other.chain().map(|cause| cause.to_string()).collect();
+        let mut error = self.wrap_all(contexts.into_iter().rev());
+        HelpMsg::merge(&mut error.help, other.help);
+        HelpMsg::merge(&mut error.hint, other.hint);
+        if let Some(extras) = other.extras {
+            let Extras {
+                retry_after,
+                any,
+                tags,
+                ..
+            } = *extras;
+            let error_extras = error.extras_mut();
+            if retry_after.is_some() {
+                error_extras.retry_after = retry_after;
+            }
+            if any.is_some() {
+                error_extras.any = any;
+            }
+            let tags = tags
+                .into_iter()
+                .map(|(position, tag)| (position + offset, tag));
+            error_extras.tags.extend(tags);
+        }
+        #[cfg(feature = "cli-error")]
+        {
+            error.exit_code = exit_code.or(error.exit_code);
         }
+        error
     }
 
     /// Returns true if `E` is the type held by this error object.
@@ -157,6 +406,11 @@ impl Error {
 
     /// Downcast this error object by reference.
     ///
+    /// Only the error held by this object and any context it has been
+    /// wrapped with are checked, not their [`source`](StdError::source)
+    /// errors. Use [`find_ref`](Error::find_ref) to search the whole
+    /// [`chain`](Error::chain).
+    ///
     /// # Example
     ///
     /// ```
@@ -199,13 +453,139 @@ impl Error {
     }
 
     /// Downcast this error object by mutable reference.
+    ///
+    /// Like [`downcast_ref`](Error::downcast_ref), [`source`](StdError::source)
+    /// errors are not checked. They only give shared references, so use
+    /// [`find_ref`](Error::find_ref) to read them instead.
     pub fn downcast_mut<E>(&mut self) -> Option<&mut E>
     where
         E: fmt::Display + fmt::Debug + Send + Sync + 'static,
     {
+        // the error may be changed into one with a different exit code
+        #[cfg(feature = "cli-error")]
+        self.exit_code_cache.take();
         self.inner.downcast_mut()
     }
 
+    /// Find the first error of type `E` in the [`chain`](Error::chain).
+    ///
+    /// Unlike [`downcast_ref`](Error::downcast_ref), this also searches the
+    /// [`source`](StdError::source) of each error, such as an
+    /// [`io::Error`](std::io::Error) wrapped inside a library's error type.
+    /// Only types implementing [`std::error::Error`] can be found this way.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::{fmt, io};
+    ///
+    /// use narrate::Error;
+    ///
+    /// #[derive(Debug)]
+    /// struct LoadError(io::Error);
+    ///
+    /// impl fmt::Display for LoadError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "cannot load")
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for LoadError {
+    ///     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    ///         Some(&self.0)
+    ///     }
+    /// }
+    ///
+    /// let io_err = io::Error::new(io::ErrorKind::NotFound, "no such file");
+    /// let error = Error::new(LoadError(io_err));
+    /// assert!(error.downcast_ref::<io::Error>().is_none());
+    /// assert_eq!(
+    ///     io::ErrorKind::NotFound,
+    ///     error.find_ref::<io::Error>().unwrap().kind()
+    /// );
+    /// ```
+    pub fn find_ref<E>(&self) -> Option<&E>
+    where
+        E: StdError + 'static,
+    {
+        self.chain().find_map(|cause| cause.downcast_ref())
+    }
+
+    /// Downcast this error object to a [`CliError`](crate::CliError) by
+    /// reference.
+    ///
+    /// Shorthand for [`downcast_ref::<CliError>`](Error::downcast_ref).
+    #[cfg(feature = "cli-error")]
+    pub fn cli_error(&self) -> Option<&crate::CliError> {
+        self.downcast_ref()
+    }
+
+    /// Downcast this error object to a [`CliError`](crate::CliError) by
+    /// mutable reference.
+    ///
+    /// Shorthand for [`downcast_mut::<CliError>`](Error::downcast_mut). Useful
+    /// for adjusting a `CliError` before reporting it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::{CliError, Error};
+    ///
+    /// let mut error = Error::new(CliError::NoUser("alice".into()));
+    /// if let Some(CliError::NoUser(user)) = error.cli_error_mut() {
+    ///     user.push_str(" (from config)");
+    /// }
+    /// assert_eq!("user not found: alice (from config)", error.to_string());
+    /// ```
+    #[cfg(feature = "cli-error")]
+    pub fn cli_error_mut(&mut self) -> Option<&mut crate::CliError> {
+        self.downcast_mut()
+    }
+
+    /// Resolve this error's [`exit_code`](crate::ExitCode::exit_code) once and
+    /// store it.
+    ///
+    /// The code found in the error chain is otherwise searched for again
+    /// whenever the chain changes, such as when the error is wrapped with a
+    /// [`CliError`](crate::CliError). After freezing, the stored code is
+    /// returned instead, even if the chain changes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::{CliError, Error, ExitCode};
+    ///
+    /// let mut error = Error::new(CliError::Config).wrap("cannot start");
+    /// error.freeze_exit_code();
+    /// assert_eq!(exitcode::CONFIG, error.exit_code());
+    /// ```
+    #[cfg(feature = "cli-error")]
+    pub fn freeze_exit_code(&mut self) {
+        self.exit_code = Some(crate::ExitCode::exit_code(self));
+    }
+
+    /// Set the code returned by [`exit_code`](crate::ExitCode::exit_code).
+    ///
+    /// Without an explicit code, an error only reports a code other than
+    /// [`exitcode::SOFTWARE`] if there is a [`CliError`](crate::CliError) in
+    /// its chain. The explicit code takes precedence over any found in the
+    /// chain, even if the error is wrapped later.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::{error_from, ExitCode};
+    ///
+    /// let error = error_from!("no changes to commit").with_exit_code(1);
+    /// assert_eq!(1, error.exit_code());
+    /// ```
+    #[cfg(feature = "cli-error")]
+    #[must_use]
+    pub fn with_exit_code(mut self, code: i32) -> Self {
+        self.exit_code = Some(code);
+        self
+    }
+
     /// An iterator of the chain of source errors contained by this Error.
     ///
     /// This iterator will visit every error in the cause chain of this error
@@ -227,10 +607,118 @@ impl Error {
     ///     None
     /// }
     /// ```
-    pub fn chain(&self) -> Chain {
+    pub fn chain(&self) -> Chain<'_> {
         self.inner.chain().into()
     }
 
+    /// An iterator of the causes of this Error.
+    ///
+    /// The same as [`chain`](Error::chain), but without the top-level error,
+    /// which is this Error's own message. These are the `cause:` lines of a
+    /// [full report](crate::report::err_full).
+    ///
+    /// The iterator's [`len`](ExactSizeIterator::len) is the number of causes,
+    /// one less than the length of the chain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let error = error_from!("connection refused").wrap("cannot fetch index");
+    /// assert_eq!(1, error.causes().len());
+    /// let causes: Vec<_> = error.causes().map(|cause| cause.to_string()).collect();
+    /// assert_eq!(vec!["connection refused"], causes);
+    /// ```
+    pub fn causes(&self) -> Chain<'_> {
+        let mut chain = self.chain();
+        chain.next();
+        chain
+    }
+
+    /// The number of errors in the [`chain`](Error::chain), including this
+    /// Error itself.
+    ///
+    /// Always at least `1`. One more than the number of
+    /// [`causes`](Error::causes).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let error = error_from!("connection refused").wrap("cannot fetch index");
+    /// assert_eq!(2, error.context_count());
+    /// ```
+    #[inline]
+    pub fn context_count(&self) -> usize {
+        self.chain().len()
+    }
+
+    /// An iterator of the [`chain`](Error::chain), paired with whether each
+    /// error is the last one.
+    ///
+    /// Useful for drawing a tree, where the last cause gets a different
+    /// connector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let error = error_from!("connection refused").wrap("cannot fetch index");
+    /// for (last, cause) in error.chain_with_last() {
+    ///     let connector = if last { "└─" } else { "├─" };
+    ///     println!("{} {}", connector, cause);
+    /// }
+    /// // ├─ cannot fetch index
+    /// // └─ connection refused
+    /// ```
+    pub fn chain_with_last(&self) -> impl Iterator<Item = (bool, &(dyn StdError + 'static))> {
+        let chain = self.chain();
+        let last = chain.len() - 1;
+        chain
+            .enumerate()
+            .map(move |(index, cause)| (index == last, cause))
+    }
+
+    /// An iterator of every part of this error to be rendered.
+    ///
+    /// Yields each error of the [`chain`](Error::chain) as an
+    /// [`ErrorElement::Cause`], followed by the help message (if any) as an
+    /// [`ErrorElement::Help`]. A hint is yielded as an [`ErrorElement::Hint`]
+    /// directly after the top-level error. Useful for rendering the whole
+    /// error in a single pass.
+    pub fn elements(&self) -> impl Iterator<Item = ErrorElement<'_>> {
+        let mut chain = self.chain();
+        let top = chain.next().map(ErrorElement::Cause);
+        top.into_iter()
+            .chain(self.hint().map(ErrorElement::Hint))
+            .chain(chain.map(ErrorElement::Cause))
+            .chain(self.help().map(ErrorElement::Help))
+    }
+
+    /// Returns true if both errors have the same chain of messages.
+    ///
+    /// Help messages and hints are ignored. Useful for removing duplicate
+    /// errors that were given different help in different places.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let first = error_from!("timeout").wrap("cannot connect");
+    /// let mut second = error_from!("timeout").wrap("cannot connect");
+    /// second.add_help("check your network");
+    /// assert!(first.same_chain_as(&second));
+    /// ```
+    pub fn same_chain_as(&self, other: &Error) -> bool {
+        self.chain()
+            .map(|cause| cause.to_string())
+            .eq(other.chain().map(|cause| cause.to_string()))
+    }
+
     /// The lowest level cause of this error &mdash; this error's cause's
     /// cause's cause etc.
     ///
@@ -240,12 +728,203 @@ impl Error {
         self.inner.root_cause()
     }
 
+    /// The error at `depth` in the chain of source errors.
+    ///
+    /// A depth of `0` is this error's top-level message, `1` its first cause
+    /// and so on. Returns `None` if the chain is not that long.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let error = error_from!("root").wrap("middle").wrap("top");
+    /// assert_eq!("middle", error.cause_at(1).unwrap().to_string());
+    /// assert!(error.cause_at(3).is_none());
+    /// ```
+    pub fn cause_at(&self, depth: usize) -> Option<&(dyn StdError + 'static)> {
+        self.chain().nth(depth)
+    }
+
+    /// Type name of the error this `Error` was created from.
+    ///
+    /// Useful to group errors by type, such as in telemetry. The name is
+    /// recorded by [`Error::new`], [`Error::msg`] and [`From`], and is not
+    /// changed by [`wrap`](Self::wrap). Errors created from an
+    /// [`anyhow::Error`], including by [`error_from!`](crate::error_from), are
+    /// named `anyhow::Error`.
+    ///
+    /// The name is provided by [`std::any::type_name`], so its exact format
+    /// is not guaranteed.
+    ///
+    /// ```
+    /// use narrate::{Error, ErrorWrap};
+    ///
+    /// let io_err = std::io::Error::new(std::io::ErrorKind::Other, "oh no");
+    /// let error = Error::new(io_err).wrap("cannot read config");
+    /// assert_eq!("std::io::error::Error", error.type_name());
+    /// ```
+    #[inline]
+    pub fn type_name(&self) -> &'static str {
+        self.extras
+            .as_ref()
+            .and_then(|extras| extras.type_name)
+            .unwrap_or(std::any::type_name::<anyhow::Error>())
+    }
+
+    /// Source location where this error was created.
+    ///
+    /// Recorded by every constructor, including [`error_from!`](crate::error_from),
+    /// [`bail!`](crate::bail), the `?` operator and
+    /// [`ErrorWrap`](crate::ErrorWrap) methods. Wrapping an `Error` keeps the
+    /// original location. Also shown by the alternate
+    /// [`Debug`](fmt::Debug) format (`{:#?}`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let error = error_from!("invalid input");
+    /// if let Some(location) = error.location() {
+    ///     eprintln!("created at {}", location);
+    /// }
+    /// ```
+    #[inline]
+    pub fn location(&self) -> Option<&'static Location<'static>> {
+        self.extras.as_ref()?.location
+    }
+
+    /// Backtrace captured when the underlying error was created, if any.
+    ///
+    /// Backtraces are only captured when the `RUST_BACKTRACE` or
+    /// `RUST_LIB_BACKTRACE` environment variable is set, as for
+    /// [`anyhow::Error`]. Also shown by the alternate [`Debug`](fmt::Debug)
+    /// format (`{:#?}`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let error = error_from!("invalid input");
+    /// if let Some(backtrace) = error.backtrace() {
+    ///     eprintln!("{}", backtrace);
+    /// }
+    /// ```
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        let backtrace = self.inner.backtrace();
+        match backtrace.status() {
+            BacktraceStatus::Captured => Some(backtrace),
+            _ => None,
+        }
+    }
+
     /// Get a reference to this error's help message
     #[inline]
     pub fn help(&self) -> Option<&str> {
         self.help.as_ref().map(AsRef::as_ref)
     }
 
+    /// Get this error's help message, independent of the error's lifetime.
+    ///
+    /// A `'static` help message is borrowed, any other is cloned. Use this to
+    /// keep the help after the error has been moved or dropped.
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let mut error = error_from!("invalid input");
+    /// error.add_help("try again");
+    /// let help = error.help_owned();
+    /// drop(error);
+    /// assert_eq!(Some("try again"), help.as_deref());
+    /// ```
+    pub fn help_owned(&self) -> Option<Cow<'static, str>> {
+        self.help.as_ref().map(|help| match help {
+            HelpMsg::Owned(msg) => Cow::Owned(msg.clone()),
+            HelpMsg::Static(msg) => Cow::Borrowed(*msg),
+        })
+    }
+
+    /// Display the error message followed by its help message.
+    ///
+    /// This is the same layout as [`report::err`](crate::report::err), without
+    /// the `error:` title or color: the help is separated from the message by
+    /// a blank line. Errors without help display just the message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let mut error = error_from!("invalid configuration");
+    /// error.add_help("run `app init` to create one");
+    /// assert_eq!(
+    ///     "invalid configuration\n\nrun `app init` to create one",
+    ///     error.display_with_help().to_string()
+    /// );
+    /// ```
+    pub fn display_with_help(&self) -> impl fmt::Display + '_ {
+        DisplayWithHelp(self)
+    }
+
+    /// Returns true if this error has a help message.
+    #[inline]
+    pub fn has_help(&self) -> bool {
+        self.help.is_some()
+    }
+
+    /// The number of lines in this error's help message.
+    ///
+    /// Counts the same lines as [`help_lines`](Error::help_lines). Returns `0`
+    /// if there is no help, and an empty help message adds no lines.
+    #[inline]
+    pub fn help_line_count(&self) -> usize {
+        self.help_lines().count()
+    }
+
+    /// An iterator of the lines of this error's help message, in the order
+    /// they were added.
+    ///
+    /// Empty if there is no help.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let mut error = error_from!("cannot connect");
+    /// error.add_help("check your network connection");
+    /// error.add_help("retry with `--offline`");
+    /// for line in error.help_lines() {
+    ///     eprintln!("help: {}", line);
+    /// }
+    /// ```
+    pub fn help_lines(&self) -> impl Iterator<Item = &str> {
+        self.help().into_iter().flat_map(str::lines)
+    }
+
+    /// Returns true if this error's help message is exactly `expected`.
+    ///
+    /// Stacked help messages are compared as a single string, joined by new
+    /// lines.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let mut error = error_from!("error");
+    /// error.add_help("first");
+    /// error.add_help("second");
+    /// assert!(error.help_is("first\nsecond"));
+    /// ```
+    #[inline]
+    pub fn help_is(&self, expected: &str) -> bool {
+        matches!(self.help, Some(ref help) if *help == expected)
+    }
+
     /// Set this error's help message to an owned [`String`]
     #[inline]
     #[deprecated]
@@ -337,16 +1016,27 @@ impl Error {
     /// }
     /// ```
     pub fn add_help(&mut self, help: &'static str) {
-        match self.help {
-            Some(HelpMsg::Owned(ref mut existing)) => {
-                existing.push('\n');
-                existing.push_str(help);
-            }
-            Some(HelpMsg::Static(existing)) => {
-                self.help = Some(HelpMsg::Owned(format!("{}\n{}", existing, help)))
-            }
+        HelpMsg::append(&mut self.help, help);
+    }
 
-            None => self.help = Some(HelpMsg::Static(help)),
+    /// Add a 'static help message to the Error, only if `cond` is true.
+    ///
+    /// Shorthand for calling [`add_help`](Self::add_help) inside an `if`.
+    /// Useful for help that only applies to some users, such as in
+    /// interactive mode.
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let interactive = false;
+    /// let mut error = error_from!("missing API key");
+    /// error.add_help_if(interactive, "run `login` to sign in");
+    /// error.add_help_if(!interactive, "set the API_KEY environment variable");
+    /// assert_eq!(Some("set the API_KEY environment variable"), error.help());
+    /// ```
+    pub fn add_help_if(&mut self, cond: bool, help: &'static str) {
+        if cond {
+            self.add_help(help);
         }
     }
 
@@ -399,20 +1089,245 @@ impl Error {
             None => f().to_string(),
         }));
     }
+
+    /// Add a computed help message, only if reports are printed to a terminal.
+    ///
+    /// The closure is not called when stderr is redirected, so expensive help,
+    /// such as searching for an installed program, is skipped in scripts and
+    /// CI. See [`report::is_terminal`](crate::report::is_terminal).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let mut error = error_from!("command not found: rg");
+    /// error.add_help_with_if_tty(|| "install ripgrep with your package manager");
+    /// ```
+    #[cfg(feature = "report")]
+    pub fn add_help_with_if_tty<C, F>(&mut self, f: F)
+    where
+        C: fmt::Display + Send + Sync + 'static,
+        F: FnOnce() -> C,
+    {
+        if crate::report::is_terminal() {
+            self.add_help_with(f);
+        }
+    }
+
+    /// Add a 'static help message to the Error, only if it has no help yet.
+    ///
+    /// Call this where errors are reported to guarantee users always get some
+    /// guidance. More specific help, such as help added where the error was
+    /// created or wrapped, is kept as is. Use
+    /// [`report::err_full_with_default_help`](crate::report::err_full_with_default_help)
+    /// to report a default without changing the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let mut error = error_from!("cannot connect");
+    /// error.ensure_help("see the troubleshooting guide");
+    /// assert_eq!(Some("see the troubleshooting guide"), error.help());
+    ///
+    /// let mut error = error_from!("cannot connect");
+    /// error.add_help("check your network connection");
+    /// error.ensure_help("see the troubleshooting guide");
+    /// assert_eq!(Some("check your network connection"), error.help());
+    /// ```
+    pub fn ensure_help(&mut self, default: &'static str) {
+        if self.help.is_none() {
+            self.help = Some(HelpMsg::Static(default));
+        }
+    }
+
+    /// Add an owned help message to the Error.
+    ///
+    /// Use this when the help was already computed and stored in a
+    /// [`String`]. Appended on a new line to any existing help, like
+    /// [`add_help`](Self::add_help).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let path = "/etc/app.toml";
+    /// let help = format!("check the permissions of {}", path);
+    ///
+    /// let mut error = error_from!("cannot read config");
+    /// error.add_help_owned(help);
+    /// assert_eq!(Some("check the permissions of /etc/app.toml"), error.help());
+    /// ```
+    pub fn add_help_owned(&mut self, help: String) {
+        HelpMsg::merge(&mut self.help, Some(HelpMsg::Owned(help)));
+    }
+
+    /// Add a static or owned help message to the Error, returning it.
+    ///
+    /// Builder form of [`add_help`](Self::add_help) and
+    /// [`add_help_owned`](Self::add_help_owned). A `&'static str` is stored
+    /// without allocating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let name = String::from("narrate");
+    /// let error = error_from!("package not found")
+    ///     .with_help("check the spelling")
+    ///     .with_help(format!("search for {} online", name));
+    /// assert_eq!(
+    ///     Some("check the spelling\nsearch for narrate online"),
+    ///     error.help()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_help(mut self, help: impl Into<Cow<'static, str>>) -> Self {
+        let help = match help.into() {
+            Cow::Borrowed(help) => HelpMsg::Static(help),
+            Cow::Owned(help) => HelpMsg::Owned(help),
+        };
+        HelpMsg::merge(&mut self.help, Some(help));
+        self
+    }
+
+    /// How long to wait before retrying the failed operation, if known.
+    #[inline]
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.extras.as_ref()?.retry_after
+    }
+
+    /// Set how long to wait before retrying the failed operation.
+    ///
+    /// Useful for rate limits and other temporary failures. The
+    /// [`report`](crate::report) module shows the delay as a `retry after:`
+    /// line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use narrate::error_from;
+    ///
+    /// let error = error_from!("rate limited").with_retry_after(Duration::from_secs(30));
+    /// assert_eq!(Some(Duration::from_secs(30)), error.retry_after());
+    /// ```
+    #[must_use]
+    pub fn with_retry_after(mut self, delay: Duration) -> Self {
+        self.extras_mut().retry_after = Some(delay);
+        self
+    }
+
+    /// Attach a value of any type to the error.
+    ///
+    /// Unlike [`downcast_ref`](Error::downcast_ref), the value does not need
+    /// to be an error. This lets a host application recover values created by
+    /// plugins whose types are only known at runtime. Replaces any previously
+    /// attached value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// struct PluginFailure {
+    ///     code: u32,
+    /// }
+    ///
+    /// let error = error_from!("plugin failed").with_any(Box::new(PluginFailure { code: 7 }));
+    /// let failure = error.downcast_any::<PluginFailure>().unwrap();
+    /// assert_eq!(7, failure.code);
+    /// ```
+    #[must_use]
+    pub fn with_any(mut self, value: Box<dyn Any + Send + Sync>) -> Self {
+        self.extras_mut().any = Some(value);
+        self
+    }
+
+    /// Get a reference to the value attached with
+    /// [`with_any`](Error::with_any), if it is of type `T`.
+    pub fn downcast_any<T>(&self) -> Option<&T>
+    where
+        T: Any,
+    {
+        self.extras.as_ref()?.any.as_deref()?.downcast_ref()
+    }
+
+    /// Group tags added by [`wrap_tagged`](Error::wrap_tagged).
+    #[cfg(feature = "report")]
+    pub(crate) fn tags(&self) -> &[(usize, &'static str)] {
+        match &self.extras {
+            Some(extras) => &extras.tags,
+            None => &[],
+        }
+    }
+
+    fn extras_mut(&mut self) -> &mut Extras {
+        self.extras.get_or_insert_with(Default::default)
+    }
+
+    /// Get a reference to this error's hint
+    #[inline]
+    pub fn hint(&self) -> Option<&str> {
+        self.hint.as_ref().map(AsRef::as_ref)
+    }
+
+    /// Add a 'static hint to the Error.
+    ///
+    /// A hint is a short tip, shown by the [`report`](crate::report) module
+    /// as a dimmed `hint:` line directly below the error message. Use help
+    /// messages for longer guidance, which is shown at the bottom of a report.
+    ///
+    /// If the Error already has a hint, the new one is appended to it (on a new
+    /// line).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use narrate::error_from;
+    ///
+    /// let mut error = error_from!("unknown command: `buidl`");
+    /// error.add_hint("did you mean `build`?");
+    /// assert_eq!(Some("did you mean `build`?"), error.hint());
+    /// ```
+    pub fn add_hint(&mut self, hint: &'static str) {
+        HelpMsg::append(&mut self.hint, hint);
+    }
 }
 
+// Guarantee `Error` stays thread-safe if its fields change.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync + 'static>() {}
+    assert_send_sync::<Error>();
+};
+
 impl<E> From<E> for Error
 where
     E: StdError + Send + Sync + 'static,
 {
+    #[track_caller]
     fn from(err: E) -> Self {
-        Self {
-            inner: err.into(),
-            help: None,
-        }
+        Self::from_anyhow(err.into()).with_type_name::<E>()
     }
 }
 
+/// Rarely used parts of an [`Error`].
+#[derive(Default)]
+pub(crate) struct Extras {
+    // where the error was created, and the type it was created from, for
+    // debugging
+    location: Option<&'static Location<'static>>,
+    type_name: Option<&'static str>,
+    retry_after: Option<Duration>,
+    any: Option<Box<dyn Any + Send + Sync>>,
+    // group tags, by position in the chain counted from the root cause
+    tags: Vec<(usize, &'static str)>,
+}
+
 #[derive(Debug)]
 pub(crate) enum HelpMsg {
     Owned(String),
@@ -425,16 +1340,41 @@ impl fmt::Display for Error {
     }
 }
 
+/// [`Display`](fmt::Display) adapter returned by [`Error::display_with_help`].
+struct DisplayWithHelp<'a>(&'a Error);
+
+impl fmt::Display for DisplayWithHelp<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)?;
+        if let Some(help) = self.0.help() {
+            write!(f, "\n\n{}", help)?;
+        }
+        Ok(())
+    }
+}
+
 impl fmt::Debug for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if f.alternate() {
-            return fmt::Debug::fmt(&self.inner, f);
+            fmt::Debug::fmt(&self.inner, f)?;
+            if let Some(location) = self.location() {
+                write!(f, "\n\nat {}", location)?;
+            }
+            if let Some(backtrace) = self.backtrace() {
+                write!(f, "\n\nStack backtrace:\n{}", backtrace)?;
+            }
+            return Ok(());
         }
 
         write!(f, "{}", self.inner)?;
 
-        for cause in self.inner.chain().skip(1) {
-            write!(f, "\nCause: {cause}")?;
+        let mut label = cause_label().chars();
+        let title: String = label
+            .next()
+            .map(|first| first.to_uppercase().chain(label).collect())
+            .unwrap_or_default();
+        for cause in self.causes() {
+            write!(f, "\n{title}: {cause}")?;
         }
 
         if let Some(ref help) = self.help {
@@ -445,6 +1385,40 @@ impl fmt::Debug for Error {
     }
 }
 
+/// The title for each cause, shared with the report module's configuration.
+fn cause_label() -> &'static str {
+    #[cfg(feature = "report")]
+    return crate::report::config().cause_label;
+    #[cfg(not(feature = "report"))]
+    "cause"
+}
+
+impl HelpMsg {
+    /// Append `msg` on a new line, or set it if there is no message yet.
+    fn append(slot: &mut Option<Self>, msg: &'static str) {
+        match slot {
+            Some(HelpMsg::Owned(ref mut existing)) => {
+                existing.push('\n');
+                existing.push_str(msg);
+            }
+            Some(HelpMsg::Static(existing)) => {
+                *slot = Some(HelpMsg::Owned(format!("{}\n{}", existing, msg)))
+            }
+            None => *slot = Some(HelpMsg::Static(msg)),
+        }
+    }
+
+    /// Append `other` on a new line, or set it if there is no message yet.
+    fn merge(slot: &mut Option<Self>, other: Option<Self>) {
+        match (slot.take(), other) {
+            (Some(existing), Some(other)) => {
+                *slot = Some(HelpMsg::Owned(format!("{}\n{}", existing, other)))
+            }
+            (existing, other) => *slot = existing.or(other),
+        }
+    }
+}
+
 impl AsRef<str> for HelpMsg {
     fn as_ref(&self) -> &str {
         match self {
@@ -0,0 +1,29 @@
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use super::Error;
+
+/// Serialized as its message, the messages of its causes and its help:
+///
+/// ```json
+/// {
+///   "message": "cannot fetch index",
+///   "causes": ["connection refused"],
+///   "help": "check your network connection"
+/// }
+/// ```
+///
+/// `help` is `null` if there is no help message. Only available with the
+/// `serde` feature.
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let causes: Vec<_> = self.causes().map(|cause| cause.to_string()).collect();
+        let mut state = serializer.serialize_struct("Error", 3)?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("causes", &causes)?;
+        state.serialize_field("help", &self.help())?;
+        state.end()
+    }
+}
@@ -1,4 +1,7 @@
-use std::fmt::Display;
+use std::{
+    convert::Infallible,
+    fmt::{self, Display},
+};
 
 use crate::{Error, ErrorWrap};
 
@@ -6,31 +9,156 @@ impl<T, E> ErrorWrap<T, E> for Result<T, E>
 where
     E: ext::StdError + Send + Sync + 'static,
 {
+    #[track_caller]
     fn wrap<C>(self, context: C) -> crate::Result<T, Error>
     where
         C: Display + Send + Sync + 'static,
     {
-        self.map_err(|err| err.ext_context(context))
+        match self {
+            Ok(value) => Ok(value),
+            Err(err) => Err(err.ext_context(context)),
+        }
     }
 
+    #[track_caller]
     fn wrap_with<C, F>(self, f: F) -> Result<T, Error>
     where
         C: Display + Send + Sync + 'static,
         F: FnOnce() -> C,
     {
-        self.map_err(|err| err.ext_context(f()))
+        match self {
+            Ok(value) => Ok(value),
+            Err(err) => Err(err.ext_context(f())),
+        }
     }
 
+    #[track_caller]
     fn add_help(self, help: &'static str) -> Result<T, Error> {
-        self.map_err(|err| err.ext_add_help(help))
+        match self {
+            Ok(value) => Ok(value),
+            Err(err) => Err(err.ext_add_help(help)),
+        }
     }
 
+    #[track_caller]
     fn add_help_with<C, F>(self, f: F) -> Result<T, Error>
     where
         C: Display + Send + Sync + 'static,
         F: FnOnce() -> C,
     {
-        self.map_err(|err| err.ext_add_help_with(f))
+        match self {
+            Ok(value) => Ok(value),
+            Err(err) => Err(err.ext_add_help_with(f)),
+        }
+    }
+
+    #[cfg(feature = "cli-error")]
+    #[track_caller]
+    fn wrap_io<F>(self, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(std::io::ErrorKind) -> crate::CliError,
+    {
+        let err = match self {
+            Ok(value) => return Ok(value),
+            Err(err) => err.ext_into_error(),
+        };
+        let kind = err
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<std::io::Error>())
+            .map(std::io::Error::kind);
+        match kind {
+            Some(kind) => Err(err.wrap(f(kind))),
+            None => Err(err),
+        }
+    }
+}
+
+impl<T> ErrorWrap<T, Infallible> for Option<T> {
+    #[track_caller]
+    fn wrap<C>(self, context: C) -> Result<T, Error>
+    where
+        C: Display + Send + Sync + 'static,
+    {
+        match self {
+            Some(value) => Ok(value),
+            None => Err(context_error(context)),
+        }
+    }
+
+    #[track_caller]
+    fn wrap_with<C, F>(self, f: F) -> Result<T, Error>
+    where
+        C: Display + Send + Sync + 'static,
+        F: FnOnce() -> C,
+    {
+        match self {
+            Some(value) => Ok(value),
+            None => Err(context_error(f())),
+        }
+    }
+
+    #[track_caller]
+    fn add_help(self, help: &'static str) -> Result<T, Error> {
+        match self {
+            Some(value) => Ok(value),
+            None => Err(missing_value().with_help(help)),
+        }
+    }
+
+    #[track_caller]
+    fn add_help_with<C, F>(self, f: F) -> Result<T, Error>
+    where
+        C: Display + Send + Sync + 'static,
+        F: FnOnce() -> C,
+    {
+        match self {
+            Some(value) => Ok(value),
+            None => {
+                let mut err = missing_value();
+                err.add_help_with(f);
+                Err(err)
+            }
+        }
+    }
+
+    #[cfg(feature = "cli-error")]
+    #[track_caller]
+    fn wrap_io<F>(self, _f: F) -> Result<T, Error>
+    where
+        F: FnOnce(std::io::ErrorKind) -> crate::CliError,
+    {
+        self.ok_or_else(missing_value)
+    }
+}
+
+/// Error for a `None`, with `context` as its message.
+#[track_caller]
+fn context_error<C>(context: C) -> Error
+where
+    C: Display + Send + Sync + 'static,
+{
+    Error::from_anyhow(anyhow::Error::msg(DisplayError(context)))
+}
+
+/// Error for a `None` without any context.
+#[track_caller]
+fn missing_value() -> Error {
+    context_error("missing value")
+}
+
+/// Context that is only [`Display`], as [`anyhow::Error::msg`] also needs
+/// [`Debug`](fmt::Debug).
+struct DisplayError<C>(C);
+
+impl<C: Display> fmt::Debug for DisplayError<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<C: Display> fmt::Display for DisplayError<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
     }
 }
 
@@ -38,6 +166,9 @@ mod ext {
     use super::*;
 
     pub trait StdError {
+        #[cfg(feature = "cli-error")]
+        fn ext_into_error(self) -> Error;
+
         fn ext_context<C>(self, context: C) -> Error
         where
             C: Display + Send + Sync + 'static;
@@ -54,6 +185,13 @@ mod ext {
     where
         E: std::error::Error + Send + Sync + 'static,
     {
+        #[cfg(feature = "cli-error")]
+        #[track_caller]
+        fn ext_into_error(self) -> Error {
+            Error::from(self)
+        }
+
+        #[track_caller]
         fn ext_context<C>(self, context: C) -> Error
         where
             C: Display + Send + Sync + 'static,
@@ -61,12 +199,14 @@ mod ext {
             Error::from(self).wrap(context)
         }
 
+        #[track_caller]
         fn ext_add_help(self, help: &'static str) -> Error {
             let mut err = Error::from(self);
             err.add_help(help);
             err
         }
 
+        #[track_caller]
         fn ext_add_help_with<C, F>(self, f: F) -> Error
         where
             C: Display + Send + Sync + 'static,
@@ -79,6 +219,11 @@ mod ext {
     }
 
     impl StdError for Error {
+        #[cfg(feature = "cli-error")]
+        fn ext_into_error(self) -> Error {
+            self
+        }
+
         fn ext_context<C>(self, context: C) -> Error
         where
             C: Display + Send + Sync + 'static,
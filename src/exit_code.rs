@@ -14,10 +14,35 @@ impl crate::ExitCode for anyhow::Error {
     }
 }
 
+/// The exit code of an [`Error`](crate::Error) is, in order of precedence:
+///
+/// 1. The explicit code, set by [`Error::from_code`](crate::Error::from_code),
+///    [`Error::from_exit_status`](crate::Error::from_exit_status),
+///    [`Error::with_exit_code`](crate::Error::with_exit_code) or
+///    [`Error::freeze_exit_code`](crate::Error::freeze_exit_code).
+/// 2. The code of the outermost [`CliError`](crate::CliError) in the error
+///    chain.
+/// 3. [`exitcode::SOFTWARE`].
+///
+/// The error chain is only searched once, until the error is changed or an
+/// [exit code mapper](crate::set_exit_code_mapper) is set.
 #[cfg(feature = "error")]
 impl crate::ExitCode for crate::Error {
     fn exit_code(&self) -> i32 {
-        self.inner.exit_code()
+        if let Some(code) = self.exit_code {
+            return code;
+        }
+        let mapped = crate::cli_error::has_exit_code_mapper();
+        match self.exit_code_cache.get() {
+            // a code found before the mapper was set may be stale
+            Some(&(code, cached_mapped)) if cached_mapped || !mapped => code,
+            Some(_) => self.inner.exit_code(),
+            None => {
+                self.exit_code_cache
+                    .get_or_init(|| (self.inner.exit_code(), mapped))
+                    .0
+            }
+        }
     }
 }
 
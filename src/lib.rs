@@ -34,6 +34,17 @@
 //!   [`exit_code`](ExitCode).
 //! - `report`: Enables reporting errors and statuses to the console with the
 //!   [`report`] module.
+//! - `syslog`: Enables reporting errors to the system log (not enabled by
+//!   default).
+//! - `unicode-width`: Justifies [`report`] status titles by their display
+//!   width, for CJK and other wide characters (not enabled by default).
+//! - `icons`: Prefixes [`report`] error, warning and hint titles with an icon
+//!   (not enabled by default).
+//! - `tracing`: Prefixes [`report`] errors with the current `tracing` span and,
+//!   with `report::SpanFieldsLayer`, its fields (not enabled by default).
+//! - `serde`: Implements `Serialize` for [`Error`] (not enabled by default).
+//! - `indicatif`: Reports above `indicatif` progress bars (not enabled by
+//!   default).
 //!
 //! ##### Example `Cargo.toml`
 //!
@@ -250,6 +261,18 @@
 //! # }
 //! ```
 //!
+//! Use [`ensure`] to return early with an error if a condition is not
+//! satisfied.
+//!
+//! ```
+//! # use std::collections::HashMap;
+//! # use narrate::{ensure, Result};
+//! # fn run(map: HashMap<&'static str, String>, key: &str) -> Result<()> {
+//! ensure!(map.contains_key(key), "unknown key: {}", key);
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## CLI Errors
 //!
 //! Use [`CliError`] for a set of common errors that can occur in a command-line
@@ -297,14 +320,18 @@
 //! # */
 //! ```
 //!
+//! Use [`set_exit_code_mapper`] to give [`CliError`]s different codes.
+//!
 
 #[cfg(feature = "error")]
 use std::fmt::Display;
 #[cfg(feature = "cli-error")]
 use std::path::PathBuf;
+#[cfg(feature = "cli-error")]
+use std::time::Duration;
 
 #[cfg(feature = "error")]
-use error::HelpMsg;
+use error::{Extras, HelpMsg};
 
 #[cfg(feature = "cli-error")]
 mod cli_error;
@@ -318,6 +345,10 @@ pub mod report;
 
 #[cfg(feature = "anyhow")]
 pub use anyhow;
+
+#[cfg(feature = "cli-error")]
+pub use cli_error::set_exit_code_mapper;
+
 #[cfg(feature = "report")]
 pub use colored;
 
@@ -330,14 +361,26 @@ pub use colored::Color;
 /// differences:
 ///
 /// - `Error` requires that the error is `Send`, `Sync`, and `'static`.
-/// - `Error` is represented as a narrow pointer &mdash; exactly one word in
-///   size instead of two.
+/// - Rarely used data such as attached values and tags is boxed separately,
+///   so `Error` stays a few words in size.
 /// - `Error` may contain a help message in order to suggest further actions a
 ///   user might take.
+///
+/// `Error` is itself `Send`, `Sync` and `'static`, so it can be sent across
+/// threads and returned from spawned tasks.
 #[cfg(feature = "error")]
 pub struct Error {
     inner: anyhow::Error,
     help: Option<HelpMsg>,
+    hint: Option<HelpMsg>,
+    // rarely used, so boxed to keep `Error` small
+    extras: Option<Box<Extras>>,
+    #[cfg(feature = "cli-error")]
+    exit_code: Option<i32>,
+    // exit code found in the chain, and whether the exit code mapper was set
+    // when it was found, reset whenever the chain may change
+    #[cfg(feature = "cli-error")]
+    exit_code_cache: std::sync::OnceLock<(i32, bool)>,
 }
 
 /// Iterator of a chain of source errors.
@@ -366,6 +409,42 @@ pub struct Chain<'a> {
     inner: anyhow::Chain<'a>,
 }
 
+/// Part of an [`Error`] to be rendered.
+///
+/// This type is the item of the iterator returned by [`Error::elements`].
+///
+/// # Example
+///
+/// ```
+/// use narrate::{Error, ErrorElement};
+///
+/// pub fn render(error: &Error) -> String {
+///     let mut out = String::new();
+///     for element in error.elements() {
+///         match element {
+///             ErrorElement::Cause(cause) => out.push_str(&format!("- {cause}\n")),
+///             ErrorElement::Help(help) => out.push_str(&format!("? {help}\n")),
+///             _ => {}
+///         }
+///     }
+///     out
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+#[cfg(feature = "error")]
+pub enum ErrorElement<'a> {
+    /// An error from the chain of source errors, starting with the top-level
+    /// error.
+    Cause(&'a (dyn std::error::Error + 'static)),
+
+    /// The error's hint.
+    Hint(&'a str),
+
+    /// The error's help message.
+    Help(&'a str),
+}
+
 /// `Result<T, Error>`
 ///
 /// This is a reasonable return type to use throughout your application.
@@ -424,7 +503,8 @@ pub struct Chain<'a> {
 #[cfg(feature = "error")]
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
-/// Provides `wrap` and `add_help` methods for [`Result`](core::result::Result).
+/// Provides `wrap` and `add_help` methods for [`Result`](core::result::Result)
+/// and [`Option`].
 ///
 /// This trait is sealed and cannot be implemented for types outside of
 /// `narrate`.
@@ -437,6 +517,25 @@ pub type Result<T, E = Error> = core::result::Result<T, E>;
 /// Use `wrap_with` and `add_help_with` methods for lazily evaluation of the
 /// added context.
 ///
+/// ## Options
+///
+/// On an [`Option`], `None` becomes an [`Error`] with the context as its
+/// message. As there is no context to use, `add_help`, `add_help_with` and
+/// `wrap_io` give `None` the message `missing value`.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use narrate::{ErrorWrap, Result};
+///
+/// fn port(config: &HashMap<&str, u16>) -> Result<u16> {
+///     config.get("port").copied().wrap("missing key: `port`")
+/// }
+///
+/// let err = port(&HashMap::new()).unwrap_err();
+/// assert_eq!("missing key: `port`", err.to_string());
+/// ```
+///
 /// # Example
 ///
 /// ```
@@ -474,6 +573,10 @@ where
     E: Send + Sync + 'static,
 {
     /// Wrap an error value with additional context.
+    ///
+    /// The context is evaluated even if there is no error. For formatted
+    /// context, use [`wrap_with`](Self::wrap_with) or the [`wrap!`] macro to
+    /// avoid allocating on the success path.
     fn wrap<C>(self, context: C) -> Result<T, Error>
     where
         C: Display + Send + Sync + 'static;
@@ -492,6 +595,31 @@ where
     where
         C: Display + Send + Sync + 'static,
         F: FnOnce() -> C;
+
+    /// Wrap an IO error value with a [`CliError`] chosen by its
+    /// [`ErrorKind`](std::io::ErrorKind).
+    ///
+    /// `f` is only called if the error chain contains an [`std::io::Error`].
+    /// Any other error is passed through without added context.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::{fs, io::ErrorKind, path::PathBuf};
+    ///
+    /// use narrate::{CliError, ErrorWrap, Result};
+    ///
+    /// fn read_config(path: PathBuf) -> Result<String> {
+    ///     fs::read_to_string(&path).wrap_io(|kind| match kind {
+    ///         ErrorKind::NotFound => CliError::InputFileNotFound(path),
+    ///         _ => CliError::ReadFile(path),
+    ///     })
+    /// }
+    /// ```
+    #[cfg(feature = "cli-error")]
+    fn wrap_io<F>(self, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(std::io::ErrorKind) -> CliError;
 }
 
 /// Provide `exit_code` method for [CliError]. Intended to be passed to
@@ -509,18 +637,40 @@ pub trait ExitCode: exit_code::private::Sealed {
 #[cfg(feature = "cli-error")]
 #[non_exhaustive]
 pub enum CliError {
+    /// File or directory already exists
+    AlreadyExists(PathBuf),
+
     /// Invalid configuration
     Config,
 
+    /// Resource conflicts with one that already exists
+    Conflict(String),
+
     /// Cannot create file
     CreateFile(PathBuf),
 
+    /// Any other error, with its own exit code
+    Custom {
+        /// Exit code returned by [`exit_code`](ExitCode::exit_code)
+        code: i32,
+        /// Message to display
+        message: String,
+    },
+
     /// Invalid input data
     InputData,
 
     /// Supplied file not found
     InputFileNotFound(PathBuf),
 
+    /// I/O operation on a file failed
+    Io {
+        /// Kind of I/O error
+        kind: std::io::ErrorKind,
+        /// File the operation was on
+        path: PathBuf,
+    },
+
     /// User not found
     NoUser(String),
 
@@ -536,6 +686,14 @@ pub enum CliError {
     /// System file not found
     OsFileNotFound(PathBuf),
 
+    /// Operation partially failed, some items succeeded
+    PartialFailure {
+        /// Number of items that succeeded
+        succeeded: usize,
+        /// Number of items that failed
+        failed: usize,
+    },
+
     /// Cannot read file
     ReadFile(PathBuf),
 
@@ -548,6 +706,9 @@ pub enum CliError {
     /// Temporary/non fatal error
     Temporary,
 
+    /// Operation timed out after the given duration
+    Timeout(Duration),
+
     /// Incorrect usage
     Usage,
 
@@ -0,0 +1,75 @@
+use std::io::{self, Write};
+
+use colored::{Color, Colorize};
+
+use super::{config, display_width, writer};
+
+/// Box drawing characters: corners (clockwise from top left), horizontal and
+/// vertical.
+const UNICODE_BORDER: [char; 6] = ['┌', '┐', '┘', '└', '─', '│'];
+const ASCII_BORDER: [char; 6] = ['*'; 6];
+
+/// Write `<title>: <msg>` inside a box, sized to fit the message.
+///
+/// Lines wider than the terminal are wrapped.
+pub(super) fn format_boxed(
+    title: &str,
+    msg: &str,
+    color: Option<Color>,
+    f: &mut impl Write,
+) -> io::Result<()> {
+    let [top_left, top_right, bottom_right, bottom_left, horizontal, vertical] =
+        match config().ascii {
+            true => ASCII_BORDER,
+            false => UNICODE_BORDER,
+        };
+
+    let text = format!("{}: {}", title, msg);
+    let lines = wrap(&text, max_width());
+    let width = lines
+        .iter()
+        .map(|line| display_width(line))
+        .max()
+        .unwrap_or(0);
+    let border: String = std::iter::repeat(horizontal).take(width + 2).collect();
+
+    writeln!(f, "{}{}{}", top_left, border, top_right)?;
+    for (i, line) in lines.iter().enumerate() {
+        let pad = width - display_width(line);
+        let title_len = title.len() + 1;
+        match color {
+            Some(color) if i == 0 && line.len() >= title_len => {
+                let (title, rest) = line.split_at(title_len);
+                let title = title.color(color).bold();
+                writeln!(f, "{} {}{}{:pad$} {}", vertical, title, rest, "", vertical)?
+            }
+            _ => writeln!(f, "{} {}{:pad$} {}", vertical, line, "", vertical)?,
+        }
+    }
+    writeln!(f, "{}{}{}", bottom_left, border, bottom_right)
+}
+
+/// Widest line that fits in a box in the terminal, read from `COLUMNS`.
+fn max_width() -> usize {
+    writer::columns().map_or(usize::MAX, |columns| columns.saturating_sub(4).max(1))
+}
+
+/// Split each line of `text` into lines no wider than `max`.
+fn wrap(text: &str, max: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for line in text.lines() {
+        let mut current = String::new();
+        let mut current_width = 0;
+        for c in line.chars() {
+            let width = display_width(c.encode_utf8(&mut [0; 4]));
+            if current_width + width > max && !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+                current_width = 0;
+            }
+            current.push(c);
+            current_width += width;
+        }
+        lines.push(current);
+    }
+    lines
+}
@@ -0,0 +1,285 @@
+use std::{
+    borrow::Cow,
+    sync::{PoisonError, RwLock},
+};
+
+use colored::Color;
+
+static CONFIG: RwLock<ReportConfig> = RwLock::new(ReportConfig::DEFAULT);
+
+/// Settings shared by all report functions.
+///
+/// Apply a configuration with [`set_config`]. Start from
+/// the default and change only the fields you need:
+///
+/// ```
+/// use narrate::report::{self, ReportConfig};
+///
+/// let mut config = ReportConfig::default();
+/// config.trailing_newline = true;
+/// report::set_config(config);
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ReportConfig {
+    /// Print a blank line after each error report.
+    ///
+    /// Useful to separate consecutive reports. Defaults to `false`.
+    pub trailing_newline: bool,
+
+    /// Title for each cause of an error, such as `"caused by"`.
+    ///
+    /// Also used (capitalized) by the [`Debug`](std::fmt::Debug) impl of
+    /// [`narrate::Error`](crate::Error). Defaults to `"cause"`.
+    pub cause_label: &'static str,
+
+    /// Report an error with exactly one cause on a single line.
+    ///
+    /// `error: <msg>: <cause>` is printed instead of separate `error:` and
+    /// `cause:` lines. Defaults to `false`.
+    pub collapse_single_cause: bool,
+
+    /// Skip the first cause when its message is identical to the error's.
+    ///
+    /// Avoids printing the same line twice when an error is accidentally
+    /// wrapped with its own message. Set to `false` to always print the full
+    /// chain. Defaults to `true`.
+    pub hide_duplicate_cause: bool,
+
+    /// Minimum width counts are right-aligned to by
+    /// [`status_count`](super::status_count).
+    ///
+    /// Defaults to `0` (no alignment).
+    pub count_width: usize,
+
+    /// Function applied to every error message, cause, help and hint before
+    /// it is reported.
+    ///
+    /// Use this to scrub secrets, such as tokens or passwords, from errors in
+    /// one place. Defaults to `None`.
+    ///
+    /// ```
+    /// use narrate::report::{self, ReportConfig};
+    ///
+    /// fn redact(msg: &str) -> String {
+    ///     msg.replace("hunter2", "********")
+    /// }
+    ///
+    /// let mut config = ReportConfig::default();
+    /// config.redactor = Some(redact);
+    /// report::set_config(config);
+    /// ```
+    pub redactor: Option<fn(&str) -> String>,
+
+    /// Number of spaces each [`section`](fn@super::section) level is indented
+    /// by.
+    ///
+    /// Defaults to `2`.
+    pub indent_width: usize,
+
+    /// Only output ASCII characters, for terminals that cannot render
+    /// Unicode symbols.
+    ///
+    /// Defaults to `false`.
+    pub ascii: bool,
+
+    /// Maximum number of causes listed by [`err_full`](super::err_full) and
+    /// [`anyhow_err_full`](super::anyhow_err_full).
+    ///
+    /// Any further causes are summarized as `… (N more causes)`. The error
+    /// itself is not changed. Defaults to `None` (no limit).
+    pub max_causes: Option<usize>,
+
+    /// Side status titles are aligned to.
+    ///
+    /// Defaults to [`Justify::Trailing`].
+    pub justify: Justify,
+
+    /// Colors for the messages of an error report.
+    ///
+    /// Defaults to no message colors.
+    pub style: Style,
+
+    /// Title word and color of each [`Severity`](super::Severity).
+    ///
+    /// Defaults to the English titles listed on [`Severity`](super::Severity).
+    pub theme: Theme,
+
+    /// Prefix error, warning and hint titles with an icon.
+    ///
+    /// Only available with the `icons` feature. Defaults to `false`.
+    #[cfg(feature = "icons")]
+    pub icons: bool,
+}
+
+/// Colors for the messages that follow report titles.
+///
+/// Titles are always colored; these colors are applied to the message after
+/// the title. Like titles, messages are only colored when printing to a TTY.
+///
+/// ```
+/// use narrate::{
+///     report::{self, ReportConfig},
+///     Color,
+/// };
+///
+/// let mut config = ReportConfig::default();
+/// config.style.cause_message = Some(Color::BrightBlack);
+/// report::set_config(config);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct Style {
+    /// Color of the message after `error:`. Defaults to `None`.
+    pub error_message: Option<Color>,
+
+    /// Color of each message after `cause:`. Defaults to `None`.
+    pub cause_message: Option<Color>,
+}
+
+/// Title word and color for each [`Severity`](super::Severity).
+///
+/// Configure the diagnostic vocabulary of an app in one place, such as to
+/// translate or rebrand it. Used by [`diagnostic`](super::diagnostic) and
+/// every error, warning and note report.
+///
+/// ```
+/// use narrate::{
+///     report::{self, ReportConfig, Title},
+///     Color,
+/// };
+///
+/// let mut config = ReportConfig::default();
+/// config.theme.error = Title::new("erreur", Color::Red);
+/// config.theme.warning = Title::new("avertissement", Color::Magenta);
+/// report::set_config(config);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Theme {
+    /// Defaults to a red `error`.
+    pub error: Title,
+
+    /// Defaults to a yellow `warning`.
+    pub warning: Title,
+
+    /// Defaults to a blue `note`.
+    pub note: Title,
+
+    /// Defaults to a cyan `info`.
+    pub info: Title,
+
+    /// Defaults to a green `help`.
+    pub help: Title,
+}
+
+/// Title word and color of one [`Severity`](super::Severity) in a [`Theme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Title {
+    /// Word printed before the message, without the trailing `:`.
+    pub word: &'static str,
+
+    /// Color of the word, when printing to a TTY.
+    pub color: Color,
+}
+
+impl Title {
+    /// Create a title from its word and color.
+    pub const fn new(word: &'static str, color: Color) -> Self {
+        Self { word, color }
+    }
+}
+
+/// Side [`status`](super::status) titles are aligned to.
+///
+/// Titles are padded to a fixed width so that the messages following them line
+/// up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Justify {
+    /// Align titles to the start of the line, in reading order.
+    ///
+    /// Suited to right-to-left languages, where the start of the line is on
+    /// the right.
+    ///
+    /// ```txt
+    /// Compiled     narrate
+    /// ```
+    Leading,
+
+    /// Align titles to the end of the padding, in the style of Cargo.
+    ///
+    /// ```txt
+    ///     Compiled narrate
+    /// ```
+    #[default]
+    Trailing,
+}
+
+impl Style {
+    const DEFAULT: Self = Self {
+        error_message: None,
+        cause_message: None,
+    };
+}
+
+impl Theme {
+    const DEFAULT: Self = Self {
+        error: Title::new("error", Color::Red),
+        warning: Title::new("warning", Color::Yellow),
+        note: Title::new("note", Color::Blue),
+        info: Title::new("info", Color::Cyan),
+        help: Title::new("help", Color::Green),
+    };
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl ReportConfig {
+    const DEFAULT: Self = Self {
+        trailing_newline: false,
+        cause_label: "cause",
+        collapse_single_cause: false,
+        hide_duplicate_cause: true,
+        count_width: 0,
+        redactor: None,
+        indent_width: 2,
+        ascii: false,
+        max_causes: None,
+        style: Style::DEFAULT,
+        theme: Theme::DEFAULT,
+        justify: Justify::Trailing,
+        #[cfg(feature = "icons")]
+        icons: false,
+    };
+}
+
+impl Default for ReportConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Replace the configuration used by all report functions.
+pub fn set_config(config: ReportConfig) {
+    *CONFIG.write().unwrap_or_else(PoisonError::into_inner) = config;
+}
+
+/// Get a copy of the current report configuration.
+pub fn config() -> ReportConfig {
+    CONFIG
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// Apply the configured [`redactor`](ReportConfig::redactor) to `msg`.
+pub(super) fn redact(msg: &str) -> Cow<'_, str> {
+    match config().redactor {
+        Some(redactor) => Cow::Owned(redactor(msg)),
+        None => Cow::Borrowed(msg),
+    }
+}
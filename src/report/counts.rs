@@ -0,0 +1,130 @@
+use std::{
+    io::Write,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use super::{writer, STDERR};
+
+static ERRORS: AtomicUsize = AtomicUsize::new(0);
+static WARNINGS: AtomicUsize = AtomicUsize::new(0);
+static NOTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Kind of report that is counted for [`summary_counts`].
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Count {
+    Error,
+    Warning,
+    Note,
+}
+
+impl Count {
+    fn counter(self) -> &'static AtomicUsize {
+        match self {
+            Count::Error => &ERRORS,
+            Count::Warning => &WARNINGS,
+            Count::Note => &NOTES,
+        }
+    }
+}
+
+pub(super) fn increment(count: Count) {
+    count.counter().fetch_add(1, Ordering::Relaxed);
+}
+
+/// Print a summary of how many errors, warnings and notes have been reported.
+///
+/// ```txt
+/// 3 errors, 1 warning
+/// ```
+///
+/// Every [`err`](super::err), [`err_full`](super::err_full),
+/// [`anyhow_err`](super::anyhow_err), [`anyhow_err_full`](super::anyhow_err_full),
+/// [`warn`](super::warn), [`warn_full`](super::warn_full) and
+/// [`note`](super::note) call is counted. Kinds that
+/// were not reported are left out, and nothing is printed if there have been
+/// no reports at all.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{error_from, report};
+///
+/// report::err(&error_from!("cannot parse line 3"));
+/// report::warn("unused variable on line 7");
+/// report::summary_counts();
+/// // error: cannot parse line 3
+/// // warning: unused variable on line 7
+/// // 1 error, 1 warning
+/// ```
+pub fn summary_counts() {
+    let counts = [
+        (Count::Error, "error"),
+        (Count::Warning, "warning"),
+        (Count::Note, "note"),
+    ];
+    let summary =
+        format_summary(counts.map(|(count, noun)| (count.counter().load(Ordering::Relaxed), noun)));
+
+    if !summary.is_empty() {
+        let (mut f, _) = writer::output();
+        writeln!(f, "{}", summary).expect(STDERR);
+    }
+}
+
+/// Join non-zero counts into a summary, such as `2 errors, 1 warning`.
+///
+/// Empty if every count is zero.
+pub(super) fn format_summary<'a>(counts: impl IntoIterator<Item = (usize, &'a str)>) -> String {
+    counts
+        .into_iter()
+        .filter(|&(n, _)| n > 0)
+        .map(|(n, noun)| format!("{} {}{}", n, noun, if n == 1 { "" } else { "s" }))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Reset the counts printed by [`summary_counts`] to zero.
+pub fn reset_counts() {
+    for counter in [&ERRORS, &WARNINGS, &NOTES] {
+        counter.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Guard that prints a [`summary_counts`] when dropped.
+///
+/// Created with [`summary_guard`].
+#[must_use = "the summary is printed when the guard is dropped"]
+#[derive(Debug)]
+pub struct SummaryGuard {
+    _private: (),
+}
+
+/// Print a [`summary_counts`] when the returned guard is dropped.
+///
+/// Create the guard at the start of `main`, so the summary is printed however
+/// `main` returns, including early returns with `?`. Like all destructors, it
+/// does not run if the process is ended with [`std::process::exit`].
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{report, Result};
+///
+/// fn main() -> Result<()> {
+///     let _summary = report::summary_guard();
+///     report::warn("config file not found, using defaults");
+///     // ...
+///     Ok(())
+/// }
+/// // warning: config file not found, using defaults
+/// // 1 warning
+/// ```
+pub fn summary_guard() -> SummaryGuard {
+    SummaryGuard { _private: () }
+}
+
+impl Drop for SummaryGuard {
+    fn drop(&mut self) {
+        summary_counts();
+    }
+}
@@ -0,0 +1,76 @@
+use std::{
+    io::Write,
+    sync::{Mutex, PoisonError},
+};
+
+use colored::Color;
+
+use super::{format_status_string, writer, STDERR};
+
+/// Title, message and repeat count of the last [`status_dedup`].
+static LAST: Mutex<Option<(String, String, usize)>> = Mutex::new(None);
+
+/// Report a status to stderr, collapsing consecutive repeats into one line.
+///
+/// The first status is printed like [`status`](super::status). Each identical
+/// status after it updates that line in place with a count when printing to a
+/// TTY, and is left out otherwise. A different title or message starts a new
+/// line.
+///
+/// ```txt
+///      Waiting for server (x5)
+/// ```
+///
+/// Only repeated `status_dedup` calls are tracked, so on a TTY any other
+/// output in between should be followed by a new status.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{report, Color};
+///
+/// for _ in 0..5 {
+///     report::status_dedup("Waiting", "for server", Color::Cyan);
+/// }
+/// report::status_dedup("Connected", "to server", Color::Green);
+/// //      Waiting for server (x5)
+/// //    Connected to server
+/// ```
+pub fn status_dedup<T, M>(title: T, msg: M, color: Color)
+where
+    T: AsRef<str>,
+    M: AsRef<str>,
+{
+    let (title, msg) = (title.as_ref(), msg.as_ref());
+    let mut last = LAST.lock().unwrap_or_else(PoisonError::into_inner);
+    let count = match last.as_mut() {
+        Some((last_title, last_msg, count)) if last_title == title && last_msg == msg => {
+            *count += 1;
+            *count
+        }
+        _ => {
+            *last = Some((title.to_owned(), msg.to_owned(), 1));
+            1
+        }
+    };
+
+    let terminal = writer::is_terminal();
+    if count > 1 && !terminal {
+        return;
+    }
+
+    let (mut f, tty) = writer::output();
+    match count {
+        1 => {
+            let line = format_status_string(title, msg, tty.then_some(color));
+            writeln!(f, "{}", line)
+        }
+        count => {
+            let msg = format!("{} (x{})", msg, count);
+            let line = format_status_string(title, msg, tty.then_some(color));
+            f.write_control("\x1b[1A\r\x1b[K")
+                .and_then(|_| writeln!(f, "{}", line))
+        }
+    }
+    .expect(STDERR);
+}
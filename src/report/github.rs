@@ -0,0 +1,72 @@
+use std::io::{stdout, Write};
+
+use super::config::redact;
+#[cfg(feature = "error")]
+use crate::Error;
+
+const STDOUT: &str = "writing to stdout";
+
+/// Report an [`Error`] as a GitHub Actions error annotation.
+///
+/// The error, each of its causes and any help message are written on separate
+/// lines of a single `::error::` workflow command to stdout. When run in a
+/// GitHub Actions workflow, the error will be shown in the job summary.
+///
+/// ```txt
+/// ::error::<msg>%0Acause: <cause>%0A%0A<help>
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// # use narrate::{error_from, report};
+/// let error = error_from!("invalid configuration");
+/// report::err_github(&error);
+/// // ::error::invalid configuration
+/// ```
+#[cfg(feature = "error")]
+pub fn err_github(err: &Error) {
+    use std::fmt::Write;
+
+    let mut msg = redact(&err.to_string()).into_owned();
+    let label = super::config().cause_label;
+    for cause in err.causes() {
+        let cause = cause.to_string();
+        write!(msg, "\n{}: {}", label, redact(&cause)).expect("writing to a String");
+    }
+    if let Some(help) = err.help() {
+        write!(msg, "\n\n{}", redact(help)).expect("writing to a String");
+    }
+    write_annotation("error", &msg);
+}
+
+/// Report a warning as a GitHub Actions warning annotation.
+///
+/// The configured [`redactor`](super::ReportConfig::redactor) is applied to
+/// `msg`.
+///
+/// ## Example
+///
+/// ```
+/// # use narrate::report;
+/// report::warn_github("config file not found, using defaults");
+/// // ::warning::config file not found, using defaults
+/// ```
+pub fn warn_github<M>(msg: M)
+where
+    M: AsRef<str>,
+{
+    write_annotation("warning", &redact(msg.as_ref()));
+}
+
+fn write_annotation(command: &str, msg: &str) {
+    let mut f = stdout().lock();
+    writeln!(f, "::{}::{}", command, escape_data(msg)).expect(STDOUT);
+}
+
+/// Escape a workflow command's data, as required by GitHub Actions.
+fn escape_data(data: &str) -> String {
+    data.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
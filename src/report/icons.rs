@@ -0,0 +1,44 @@
+use std::borrow::Cow;
+
+#[cfg(feature = "icons")]
+use super::config;
+
+/// Icon shown before a report title, when the `icons` feature is enabled.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Icon {
+    Error,
+    Warning,
+    #[cfg_attr(not(feature = "error"), allow(dead_code))]
+    Hint,
+}
+
+#[cfg(feature = "icons")]
+impl Icon {
+    fn glyph(self, ascii: bool) -> &'static str {
+        match (self, ascii) {
+            (Icon::Error, false) => "❌",
+            (Icon::Warning, false) => "⚠️",
+            (Icon::Hint, false) => "💡",
+            (Icon::Error, true) => "[x]",
+            (Icon::Warning, true) => "[!]",
+            (Icon::Hint, true) => "[?]",
+        }
+    }
+}
+
+/// Prefix `title` with `icon`, if icons are enabled.
+#[cfg(feature = "icons")]
+pub(super) fn with_icon(title: &str, icon: Icon) -> Cow<'_, str> {
+    let config = config();
+    if !config.icons || std::env::var_os("NO_EMOJI").is_some() {
+        return Cow::Borrowed(title);
+    }
+    Cow::Owned(format!("{} {}", icon.glyph(config.ascii), title))
+}
+
+/// Prefix `title` with `icon`, if icons are enabled.
+#[cfg(not(feature = "icons"))]
+#[inline]
+pub(super) fn with_icon(title: &str, _icon: Icon) -> Cow<'_, str> {
+    Cow::Borrowed(title)
+}
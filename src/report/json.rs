@@ -0,0 +1,72 @@
+use std::io::{self, Write};
+
+use super::config;
+use crate::Error;
+
+/// Write an [`Error`] to `w` as a single line of JSON.
+///
+/// The object has the same fields as the `serde` serialization of
+/// [`Error`], plus its exit code with the `cli-error` feature:
+///
+/// ```json
+/// {"message":"cannot fetch index","causes":["connection refused"],"help":null,"exit_code":70}
+/// ```
+///
+/// `help` is `null` if there is no help message. Messages, causes and help are
+/// passed through the configured [`redactor`](super::ReportConfig::redactor).
+/// Does not need the `serde` feature, and is never colored.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{error_from, report};
+///
+/// let error = error_from!("connection refused").wrap("cannot fetch index");
+/// let mut out = Vec::new();
+/// report::err_json(&error, &mut out).unwrap();
+/// # #[cfg(feature = "cli-error")]
+/// assert_eq!(
+///     r#"{"message":"cannot fetch index","causes":["connection refused"],"help":null,"exit_code":70}"#,
+///     String::from_utf8(out).unwrap().trim_end()
+/// );
+/// ```
+pub fn err_json(err: &Error, w: &mut impl Write) -> io::Result<()> {
+    write!(w, "{{\"message\":")?;
+    write_string(&config::redact(&err.to_string()), w)?;
+
+    write!(w, ",\"causes\":[")?;
+    for (i, cause) in err.causes().enumerate() {
+        if i > 0 {
+            write!(w, ",")?;
+        }
+        write_string(&config::redact(&cause.to_string()), w)?;
+    }
+
+    write!(w, "],\"help\":")?;
+    match err.help() {
+        Some(help) => write_string(&config::redact(help), w)?,
+        None => write!(w, "null")?,
+    }
+
+    #[cfg(feature = "cli-error")]
+    write!(w, ",\"exit_code\":{}", crate::ExitCode::exit_code(err))?;
+
+    writeln!(w, "}}")
+}
+
+/// Write `s` as a JSON string, with quotes and escapes.
+fn write_string(s: &str, w: &mut impl Write) -> io::Result<()> {
+    write!(w, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(w, "\\\"")?,
+            '\\' => write!(w, "\\\\")?,
+            '\n' => write!(w, "\\n")?,
+            '\r' => write!(w, "\\r")?,
+            '\t' => write!(w, "\\t")?,
+            c if c.is_control() => write!(w, "\\u{:04x}", u32::from(c))?,
+            c => write!(w, "{}", c)?,
+        }
+    }
+    write!(w, "\"")
+}
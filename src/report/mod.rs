@@ -0,0 +1,1520 @@
+//! Functions for printing status and error messages to stderr.
+//!
+//! ## Report status
+//!
+//! Similar to [Cargo](https://github.com/rust-lang/cargo/) output, a [`status`]
+//! title is justified, colored and made bold. Coloring is provided by the
+//! [`Color`] enum.
+//!
+//! ## Report errors
+//!
+//! Use [`err`] or [`anyhow_err`] to print error information from either a
+//! [`narrate::Error`](Error) or an [`anyhow::Error`] respectively. Include
+//! error chains/causes in your output by using [`err_full`] or
+//! [`anyhow_err_full`].
+//!
+//! ## Features
+//!
+//! If you have no desire to use any of narrate's other features, you can use
+//! just this module's functionality by disabling the default features and just
+//! using "report".
+//!
+//! ```toml
+//! [dependencies]
+//! narrate = { version = "0.4.2", default-features = false, features = ["report"] }
+//! ```
+//!
+//! This will still allow you to report [anyhow errors](anyhow), but not [narrate
+//! errors](Error).
+//!
+//! ## Report warnings and notes
+//!
+//! Use [`warn`] and [`note`] for messages that are not fatal errors.
+//!
+//! Call [`summary_counts`] at the end of a run to print how many errors,
+//! warnings and notes were reported, or create a [`summary_guard`] to print
+//! them when it is dropped.
+//!
+//! ## Sections
+//!
+//! Group related output by indenting it with a [`section()`].
+//!
+//! ## GitHub Actions
+//!
+//! Use [`err_github`] and [`warn_github`] to print errors and warnings as
+//! [GitHub Actions workflow
+//! commands](https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions),
+//! so they are shown as annotations when run in CI.
+//!
+//! ## System log
+//!
+//! With the `syslog` feature enabled, [`err_syslog`] sends a [`narrate
+//! error`](Error) to the local syslog daemon instead of the console.
+//!
+//! ## Icons
+//!
+//! With the `icons` feature enabled, error, warning and hint titles can be
+//! prefixed by an icon, such as `❌ error:`. Turn them on at runtime with
+//! `ReportConfig::icons`. Setting the `NO_EMOJI` environment variable turns
+//! them off again, and ASCII fallbacks are used when [`ReportConfig::ascii`]
+//! is set.
+//!
+//! ## Color
+//!
+//! Titles are colored when stderr is a TTY. Set the `NO_COLOR` environment
+//! variable to turn color off, or `CLICOLOR_FORCE` to turn it on even when
+//! stderr is not a TTY. `NO_COLOR` takes precedence.
+//!
+//! Apps with a `--color` flag can override both with [`set_color_choice`].
+//!
+//! ## Output destination
+//!
+//! Reports are printed to stderr by default. Use [`with_writer`] to send them
+//! somewhere else, such as a log file, or [`set_tee`] to also copy them to a
+//! file without color.
+//!
+//! Each report is written in full before its function returns. When mixing
+//! reports with output to stdout, call [`flush`] to keep the two in order.
+//!
+//! ## Progress bars
+//!
+//! With the `indicatif` feature enabled, use [`status_via`] and `err_via` to
+//! report above active [`indicatif`](https://docs.rs/indicatif) progress bars
+//! without corrupting them.
+//!
+//! ## Tracing
+//!
+//! With the `tracing` feature enabled, error reports made inside a
+//! [`tracing`](https://docs.rs/tracing) span are prefixed with the span's
+//! name, such as `error: fetch: connection refused`. Add [`SpanFieldsLayer`]
+//! to the subscriber to show the span's fields too, as in
+//! `error: fetch{attempt=2}: connection refused`. Output is unchanged when no
+//! span is entered.
+//!
+//! ## Configuration
+//!
+//! Output can be adjusted for all report functions with [`set_config`]. See
+//! [`ReportConfig`] for the available settings.
+
+use std::{
+    fmt::Display,
+    io::{self, stderr, Write},
+    time::{Duration, Instant},
+};
+
+use colored::{Color, Colorize};
+
+#[cfg(feature = "error")]
+use crate::Error;
+use counts::Count;
+#[cfg(feature = "error")]
+use icons::Icon;
+
+pub use config::{config, set_config, Justify, ReportConfig, Style, Theme, Title};
+pub use counts::{reset_counts, summary_counts, summary_guard, SummaryGuard};
+pub use dedup::status_dedup;
+#[cfg(feature = "error")]
+pub use github::err_github;
+pub use github::warn_github;
+#[cfg(feature = "error")]
+pub use json::err_json;
+#[cfg(all(feature = "error", feature = "indicatif"))]
+pub use multi_progress::err_via;
+#[cfg(feature = "indicatif")]
+pub use multi_progress::status_via;
+#[cfg(feature = "error")]
+pub use render::{render_all, render_err_to_fmt};
+pub use section::{section, Section};
+pub use severity::Severity;
+#[cfg(feature = "tracing")]
+pub use span::SpanFieldsLayer;
+#[cfg(feature = "syslog")]
+pub use syslog::Facility;
+#[cfg(all(feature = "error", feature = "syslog"))]
+pub use system_log::err_syslog;
+pub use tee::{clear_tee, set_tee};
+pub use writer::{is_terminal, set_color_choice, with_writer, ColorChoice, Stream};
+
+#[cfg(feature = "error")]
+mod boxed;
+mod config;
+mod counts;
+mod dedup;
+mod github;
+mod icons;
+#[cfg(feature = "error")]
+mod json;
+#[cfg(feature = "indicatif")]
+mod multi_progress;
+#[cfg(feature = "error")]
+mod render;
+mod section;
+mod severity;
+#[cfg(feature = "tracing")]
+mod span;
+#[cfg(all(feature = "error", feature = "syslog"))]
+mod system_log;
+mod tee;
+mod writer;
+
+const STDERR: &str = "writing to stderr";
+const STDOUT: &str = "writing to stdout";
+
+/// Report a status to stderr.
+///
+/// ```txt
+///     <title>: <msg>
+/// ```
+///
+/// The title will be justified in the style of Cargo's statuses. If stderr
+/// is directed to a TTY (as is normal for a CLI app), it will have it's color
+/// set.
+///
+/// Any [`Color`] can be used, including exact colors with
+/// [`Color::TrueColor`]. See also [`status_rgb`].
+///
+/// Enable the `unicode-width` feature to justify titles containing wide
+/// characters (such as CJK) by their display width.
+pub fn status<T, M>(title: T, msg: M, color: Color)
+where
+    T: AsRef<str>,
+    M: AsRef<str>,
+{
+    status_stream(Stream::Stderr, title, msg, color);
+}
+
+/// Report a status to `stream`.
+///
+/// The same as [`status`], but the title is only colored if `stream` is
+/// directed to a TTY. A writer set with [`with_writer`] only replaces
+/// [`Stream::Stderr`].
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{
+///     report::{self, Stream},
+///     Color,
+/// };
+///
+/// let json = false; // e.g. from a `--json` flag
+/// let stream = match json {
+///     true => Stream::Stderr,
+///     false => Stream::Stdout,
+/// };
+/// report::status_stream(stream, "Checked", "42 files", Color::Green);
+/// ```
+pub fn status_stream<T, M>(stream: Stream, title: T, msg: M, color: Color)
+where
+    T: AsRef<str>,
+    M: AsRef<str>,
+{
+    let (mut f, tty) = writer::output_to(stream);
+    let color = tty.then_some(color);
+    let result = format_status(0, title, msg.as_ref(), color, &mut f);
+    match stream {
+        Stream::Stdout => result.expect(STDOUT),
+        Stream::Stderr => result.expect(STDERR),
+    }
+}
+
+/// Report a status to stdout.
+///
+/// Shorthand for [`status_stream`] with [`Stream::Stdout`], for tools that
+/// print their progress to stdout and keep stderr for errors.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{report, Color};
+///
+/// report::status_stdout("Formatted", "src/lib.rs", Color::Green);
+/// ```
+pub fn status_stdout<T, M>(title: T, msg: M, color: Color)
+where
+    T: AsRef<str>,
+    M: AsRef<str>,
+{
+    status_stream(Stream::Stdout, title, msg, color);
+}
+
+/// Write a status to `w`, in the same format as [`status`].
+///
+/// Unlike [`status`], whether the title is colored is up to the caller: pass
+/// `None` for plain output. Useful for capturing output in tests or writing it
+/// to a log file.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{report, Color};
+///
+/// let mut out = Vec::new();
+/// report::status_to(&mut out, "Compiled", "narrate", None).unwrap();
+/// assert_eq!(b"    Compiled narrate\n", out.as_slice());
+/// # report::status_to(&mut out, "Compiled", "narrate", Some(Color::Green)).unwrap();
+/// ```
+pub fn status_to<W, T, M>(w: &mut W, title: T, msg: M, color: Option<Color>) -> io::Result<()>
+where
+    W: Write,
+    T: AsRef<str>,
+    M: AsRef<str>,
+{
+    format_status(0, title, msg.as_ref(), color, w)
+}
+
+/// Report a status to stderr, indented by `indent` spaces.
+///
+/// The same as [`status`], but the justified title is moved right, so the
+/// statuses of sub-tasks can be nested under their parent's.
+///
+/// ```txt
+///    Compiling narrate
+///        Compiled parser
+///        Compiled report
+/// ```
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{report, Color};
+///
+/// report::status("Compiling", "narrate", Color::Green);
+/// report::status_indent(4, "Compiled", "parser", Color::Green);
+/// report::status_indent(4, "Compiled", "report", Color::Green);
+/// ```
+pub fn status_indent<T, M>(indent: usize, title: T, msg: M, color: Color)
+where
+    T: AsRef<str>,
+    M: AsRef<str>,
+{
+    let (mut f, tty) = writer::output();
+    let color = tty.then_some(color);
+    format_status(indent, title, msg.as_ref(), color, &mut f).expect(STDERR);
+}
+
+/// Report a status to stderr, with an exact RGB title color.
+///
+/// Shorthand for [`status`] with [`Color::TrueColor`]. Like every status,
+/// the title is only colored if stderr is directed to a TTY.
+///
+/// ```
+/// use narrate::report;
+///
+/// report::status_rgb("Deployed", "acme-api", 255, 136, 0);
+/// ```
+pub fn status_rgb<T, M>(title: T, msg: M, r: u8, g: u8, b: u8)
+where
+    T: AsRef<str>,
+    M: AsRef<str>,
+{
+    status(title, msg, Color::TrueColor { r, g, b });
+}
+
+/// Report a status to stderr, with any [`Display`] message.
+///
+/// The same as [`status`], but avoids converting values such as numbers or
+/// [`Path::display`](std::path::Path::display) into a `String` first.
+///
+/// ```
+/// use narrate::{report, Color};
+///
+/// let count = 42;
+/// report::status_display("Compiled", count, Color::Green);
+/// ```
+pub fn status_display<T, M>(title: T, msg: M, color: Color)
+where
+    T: AsRef<str>,
+    M: Display,
+{
+    let (mut f, tty) = writer::output();
+    let color = tty.then_some(color);
+    format_status(0, title, msg, color, &mut f).expect(STDERR);
+}
+
+/// Report a skipped item as a status to stderr.
+///
+/// ```txt
+///      Skipped <msg>
+/// ```
+///
+/// The `Skipped` title is gray, to set no-op items apart from other statuses.
+///
+/// ```
+/// use narrate::report;
+///
+/// report::status_skipped("narrate (up to date)");
+/// ```
+pub fn status_skipped<M>(msg: M)
+where
+    M: AsRef<str>,
+{
+    status("Skipped", msg, Color::BrightBlack);
+}
+
+/// Report a finished step as a green status to stderr.
+///
+/// ```txt
+///         Done <msg>
+/// ```
+///
+/// ```
+/// use narrate::report;
+///
+/// report::status_done("building docs");
+/// ```
+pub fn status_done<M>(msg: M)
+where
+    M: AsRef<str>,
+{
+    status("Done", msg, Color::Green);
+}
+
+/// Report a failed step as a red status to stderr.
+///
+/// ```txt
+///       Failed <msg>
+/// ```
+///
+/// Use this for a step that failed without ending the program.
+///
+/// ```
+/// use narrate::report;
+///
+/// report::status_failed("uploading docs");
+/// ```
+pub fn status_failed<M>(msg: M)
+where
+    M: AsRef<str>,
+{
+    status("Failed", msg, Color::Red);
+}
+
+/// Format a status line, as printed by [`status`], into a `String`.
+///
+/// The line is not terminated by a newline. Color is only applied if `color`
+/// is `Some`, so the caller decides whether their destination supports it.
+///
+/// ```
+/// use narrate::report;
+///
+/// let line = report::format_status_string("Compiled", "narrate", None);
+/// assert_eq!("    Compiled narrate", line);
+/// ```
+pub fn format_status_string<T, M>(title: T, msg: M, color: Option<Color>) -> String
+where
+    T: AsRef<str>,
+    M: AsRef<str>,
+{
+    let mut buf = Vec::new();
+    format_status(0, title, msg.as_ref(), color, &mut buf).expect("write to Vec cannot fail");
+    buf.pop(); // newline
+    String::from_utf8(buf).expect("status is valid UTF-8")
+}
+
+/// Report a status to stderr, prefixed by a glyph.
+///
+/// ```txt
+/// <glyph> <title> <msg>
+/// ```
+///
+/// Unlike [`status`], the title is not justified. If stderr is directed to a
+/// TTY, the title will have its color set. When [`ReportConfig::ascii`] is
+/// set, the glyph is replaced by a space for terminals that cannot render it.
+///
+/// ```
+/// use narrate::{report, Color};
+///
+/// report::status_glyph("✔", "Done", "all tasks complete", Color::Green);
+/// // ✔ Done all tasks complete
+/// ```
+pub fn status_glyph<T, M>(glyph: &str, title: T, msg: M, color: Color)
+where
+    T: AsRef<str>,
+    M: AsRef<str>,
+{
+    let (mut f, tty) = writer::output();
+    let color = tty.then_some(color);
+    let glyph = if config().ascii { " " } else { glyph };
+    let title = title.as_ref();
+    let msg = msg.as_ref();
+    match color {
+        Some(color) => writeln!(f, "{} {} {}", glyph, title.color(color).bold(), msg),
+        None => writeln!(f, "{} {} {}", glyph, title, msg),
+    }
+    .expect(STDERR);
+}
+
+/// Report a count of items as a status to stderr.
+///
+/// ```txt
+///     <title> <count> <noun>
+/// ```
+///
+/// The noun is pluralized by appending an `s`, unless `count` is `1`. The
+/// count is right-aligned to [`ReportConfig::count_width`] columns, so a batch
+/// of counts line up.
+///
+/// ```
+/// use narrate::{report, Color};
+///
+/// report::status_count("Compiled", 42, "file", Color::Green);
+/// report::status_count("Skipped", 1, "file", Color::Yellow);
+/// //     Compiled 42 files
+/// //      Skipped 1 file
+/// ```
+pub fn status_count<T>(title: T, count: usize, noun: &str, color: Color)
+where
+    T: AsRef<str>,
+{
+    let plural = if count == 1 { "" } else { "s" };
+    let width = config().count_width;
+    let msg = format!("{:>width$} {}{}", count, noun, plural);
+    status_display(title, msg, color);
+}
+
+/// Report progress as a percentage status to stderr.
+///
+/// ```txt
+///  Downloading [ 42%] 42/100
+/// ```
+///
+/// If stderr is directed to a TTY, each call overwrites the previous progress
+/// line, and the line is finished once `done` reaches `total`. Otherwise, only
+/// the finished line is printed, so logs are not flooded with progress
+/// updates.
+///
+/// ```
+/// use narrate::{report, Color};
+///
+/// for done in 0..=100 {
+///     report::status_percent("Downloading", done, 100, Color::Cyan);
+/// }
+/// ```
+pub fn status_percent<T>(title: T, done: u64, total: u64, color: Color)
+where
+    T: AsRef<str>,
+{
+    format_percent(title.as_ref(), done, total, None, color);
+}
+
+/// Report progress as a percentage status to stderr, with an estimated time
+/// remaining.
+///
+/// ```txt
+///  Downloading [ 42%] 42/100 (eta 1m 05s)
+/// ```
+///
+/// The estimate assumes the remaining work progresses at the same rate as
+/// since `start`. It is left out until some work is done, once finished, and
+/// if it is too large to represent. Otherwise the same as [`status_percent`].
+///
+/// ```
+/// use std::time::Instant;
+///
+/// use narrate::{report, Color};
+///
+/// let start = Instant::now();
+/// for done in 0..=100 {
+///     report::status_percent_eta("Downloading", done, 100, start, Color::Cyan);
+/// }
+/// ```
+pub fn status_percent_eta<T>(title: T, done: u64, total: u64, start: Instant, color: Color)
+where
+    T: AsRef<str>,
+{
+    format_percent(title.as_ref(), done, total, Some(start), color);
+}
+
+/// Report a status to stderr, with an annotation at the right edge of the
+/// terminal.
+///
+/// ```txt
+///    Compiling narrate                                   1.2s
+/// ```
+///
+/// Useful for per-step timings or sizes. The annotation is dimmed when the
+/// status is colored. The terminal width is read from the `COLUMNS`
+/// environment variable; if it is unknown, or the line does not fit, the
+/// annotation follows the message after two spaces instead.
+///
+/// ```
+/// use narrate::{report, Color};
+///
+/// report::status_annotated("Compiling", "narrate", "1.2s", Color::Green);
+/// ```
+pub fn status_annotated<T, M>(title: T, msg: M, right: &str, color: Color)
+where
+    T: AsRef<str>,
+    M: AsRef<str>,
+{
+    let (title, msg) = (title.as_ref(), msg.as_ref());
+    let (mut f, tty) = writer::output();
+    let width = display_width(&format_status_string(title, msg, None)) + display_width(right);
+    let pad = writer::columns()
+        .map(|columns| columns.saturating_sub(f.indent() + width))
+        .filter(|&pad| pad >= 2)
+        .unwrap_or(2);
+    let line = format_status_string(title, msg, tty.then_some(color));
+    match tty {
+        true => writeln!(f, "{}{:pad$}{}", line, "", right.dimmed()),
+        false => writeln!(f, "{}{:pad$}{}", line, "", right),
+    }
+    .expect(STDERR);
+}
+
+/// Print a banner, such as a tool's name and version, to stderr.
+///
+/// Each line is centered within the terminal width, read from the `COLUMNS`
+/// environment variable. If the width is unknown, lines are left-aligned.
+/// Lines are bold and colored with `color` if stderr is directed to a TTY.
+///
+/// ```
+/// use narrate::{report, Color};
+///
+/// report::banner(&["narrate 0.4.2", "the friendly CLI"], Color::Cyan);
+/// ```
+pub fn banner(lines: &[&str], color: Color) {
+    let (mut f, tty) = writer::output();
+    let columns = writer::columns().map(|columns| columns.saturating_sub(f.indent()));
+    for line in lines {
+        let pad = columns.map_or(0, |columns| columns.saturating_sub(display_width(line)) / 2);
+        match tty {
+            true => writeln!(f, "{:pad$}{}", "", line.color(color).bold()),
+            false => writeln!(f, "{:pad$}{}", "", line),
+        }
+        .expect(STDERR);
+    }
+}
+
+/// Report an [`Error`] to stderr.
+///
+/// The message will consist of a red `error:` title, followed by the
+/// [`Display`] impl for the underlying error.
+///
+/// If the [`Error`] contains a help message, that will be printed 2 lines
+/// below. A [hint](Error::add_hint) is printed as a dimmed `hint:` line
+/// directly below the message.
+///
+/// ## Examples
+///
+/// Standard error.
+///
+/// ```
+/// # use narrate::error_from;
+/// # use narrate::report;
+/// let error = error_from!("invalid configuration");
+/// # /*
+/// report::err(&error);
+/// # */
+/// // error: invalid configuration
+/// ```
+///
+/// Error with a help message.
+///
+/// ```
+/// # use narrate::CliError;
+/// # use narrate::Error;
+/// # use narrate::report;
+/// let mut error = Error::new(CliError::Config);
+/// error.add_help("try something else");
+/// # /*
+/// report::err(&error);
+/// # */
+/// // error: invalid configuration
+/// //
+/// // try something else
+/// ```
+#[cfg(feature = "error")]
+pub fn err(err: &Error) {
+    counts::increment(Count::Error);
+    let (mut f, color) = writer::output();
+    err_to(&mut f, err, color).expect(STDERR);
+}
+
+/// Report an [`Error`] to stderr like [`err`], but with every line of its
+/// help.
+///
+/// [`err`] only prints the last help message, and [`err_full`] also lists
+/// the causes. Use this to give users all the stacked help suggestions
+/// without showing them the cause chain.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{error_from, report};
+///
+/// let error = error_from!("connection refused")
+///     .with_help("check your network connection")
+///     .wrap("cannot fetch index")
+///     .with_help("run with `--offline` to use the cached index");
+/// # /*
+/// report::err_with_full_help(&error);
+/// # */
+/// // error: cannot fetch index
+/// //
+/// // check your network connection
+/// // run with `--offline` to use the cached index
+/// ```
+#[cfg(feature = "error")]
+pub fn err_with_full_help(err: &Error) {
+    counts::increment(Count::Error);
+    let (mut f, color) = writer::output();
+    format_error_title(err.to_string(), color, &mut f).expect(STDERR);
+    format_error_hint(err, color, &mut f).expect(STDERR);
+    format_error_retry_after(err, &mut f).expect(STDERR);
+    format_error_help_all(err.help(), &mut f).expect(STDERR);
+    format_error_end(&mut f).expect(STDERR);
+}
+
+/// Write an [`Error`] to `w`, in the same format as [`err`].
+///
+/// Titles are colored if `color` is `true`. The error is not counted by
+/// [`summary_counts`].
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{error_from, report};
+///
+/// let mut out = Vec::new();
+/// report::err_to(&mut out, &error_from!("invalid configuration"), false).unwrap();
+/// assert_eq!(b"error: invalid configuration\n", out.as_slice());
+/// ```
+#[cfg(feature = "error")]
+pub fn err_to<W: Write>(w: &mut W, err: &Error, color: bool) -> io::Result<()> {
+    format_error_title(err.to_string(), color, w)?;
+    format_error_hint(err, color, w)?;
+    format_error_retry_after(err, w)?;
+    format_error_help(err, w)?;
+    format_error_end(w)
+}
+
+/// Report an [`Error`] to stderr, inside a box.
+///
+/// A high visibility alternative to [`err`] for fatal errors. The box is
+/// sized to the message, and lines wider than the terminal (read from the
+/// `COLUMNS` environment variable) are wrapped. When [`ReportConfig::ascii`]
+/// is set, the box is drawn with `*` characters.
+///
+/// If the [`Error`] contains a help message, that will be printed 2 lines
+/// below the box.
+///
+/// ## Example
+///
+/// ```
+/// # use narrate::error_from;
+/// # use narrate::report;
+/// let error = error_from!("database is corrupt");
+/// # /*
+/// report::err_boxed(&error);
+/// # */
+/// // ┌────────────────────────────┐
+/// // │ error: database is corrupt │
+/// // └────────────────────────────┘
+/// ```
+#[cfg(feature = "error")]
+pub fn err_boxed(err: &Error) {
+    counts::increment(Count::Error);
+    let (mut f, color) = writer::output();
+    let title = config().theme.error;
+    let color = match color {
+        true => Some(title.color),
+        false => None,
+    };
+    let msg = config::redact(&err.to_string()).into_owned();
+    boxed::format_boxed(title.word, &msg, color, &mut f).expect(STDERR);
+    format_error_help(err, &mut f).expect(STDERR);
+    format_error_end(&mut f).expect(STDERR);
+}
+
+/// Report an [`Error`] to stderr, printing a list of causes
+///
+/// The message will consist of a red `error:` title, followed by the
+/// [`Display`] impl for the underlying error.
+/// Each subsequent wrapped error will have a plain `cause:` title (see
+/// [`ReportConfig::cause_label`]). A [hint](Error::add_hint) is printed as a
+/// dimmed `hint:` line before the causes.
+///
+/// ## Examples
+///
+/// Wrapped error.
+///
+/// ```
+/// # fn parse_config_file(path: &str) -> narrate::Result<()> {
+/// #   Ok(())
+/// # }
+/// use narrate::{report, ErrorWrap, Result};
+///
+/// fn setup_config() -> Result<()> {
+/// # /*
+///     ...
+/// # */
+/// # let path = "";
+///     let user_config = parse_config_file(&path)
+///         .wrap_with(|| format!("invalid config file: `{}`", &path))?;
+/// # /*
+///     ...
+/// # */
+/// # Ok(())
+/// }
+///
+/// fn main() {
+/// # /*
+///     ...
+/// # */
+///     let res = setup_config().wrap("invalid configuration");
+///     if let Err(ref err) = res {
+/// # /*
+///         report::err_full(err);
+/// # */
+///         // error: invalid configuration
+///         // cause: invalid config file: `config.toml`
+///         // cause: missing key: `author`
+///     }
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// Wrapped error with a help message.
+///
+/// ```
+/// use std::{fs::File, path::PathBuf};
+///
+/// use narrate::{report, CliError, ErrorWrap, Result};
+///
+/// fn run() -> Result<()> {
+///     let path = PathBuf::from("/nopermission/file.txt");
+///     File::create(&path)
+///         .wrap_with(|| CliError::CreateFile(path))
+///         .add_help("try using a valid file name")?;
+///     Ok(())
+/// }
+///
+///
+/// fn main() {
+///     if let Err(err) = run() {
+/// # /*
+///         report::err_full(&err);
+/// # */
+///         // error: cannot create file: "/nopermission.txt"
+///         // cause: permission denied
+///         //
+///         // try using a valid file name
+///     }
+/// }
+/// ```
+#[cfg(feature = "error")]
+pub fn err_full(err: &Error) {
+    diagnostic_full(Severity::Error, err);
+}
+
+/// Write an [`Error`] to `w`, in the same format as [`err_full`].
+///
+/// Titles are colored if `color` is `true`. The error is not counted by
+/// [`summary_counts`]. See also [`render_err_to_fmt`] to write to a
+/// [`fmt::Write`](std::fmt::Write).
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{error_from, report, ErrorWrap};
+///
+/// let error = error_from!("connection refused").wrap("cannot fetch index");
+/// let mut out = Vec::new();
+/// report::err_full_to(&mut out, &error, false).unwrap();
+/// assert_eq!(
+///     b"error: cannot fetch index\ncause: connection refused\n",
+///     out.as_slice()
+/// );
+/// ```
+#[cfg(feature = "error")]
+pub fn err_full_to<W: Write>(w: &mut W, err: &Error, color: bool) -> io::Result<()> {
+    format_err_full(err, color, w)
+}
+
+/// Report an [`Error`] to stderr like [`err_full`], with `default` help if
+/// the error has none.
+///
+/// Use this at the reporting boundary to make sure every error shown to the
+/// user comes with some guidance. The error is not changed; see
+/// [`Error::ensure_help`] to attach the default instead.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{error_from, report};
+///
+/// let error = error_from!("unexpected end of file");
+/// # /*
+/// report::err_full_with_default_help(&error, "run with `--verbose` for details");
+/// # */
+/// // error: unexpected end of file
+/// //
+/// // run with `--verbose` for details
+/// ```
+#[cfg(feature = "error")]
+pub fn err_full_with_default_help(err: &Error, default: &str) {
+    counts::increment(Count::Error);
+    let (mut f, color) = writer::output();
+    let help = Some(err.help().unwrap_or(default));
+    format_diagnostic_full(Severity::Error, err, help, color, &mut f).expect(STDERR);
+}
+
+/// Report the pretty [`Debug`](std::fmt::Debug) form of an [`Error`] to
+/// stderr.
+///
+/// This is the alternate (`{:#?}`) output of the underlying
+/// [`anyhow::Error`], including its backtrace if one was captured. Intended
+/// for deep troubleshooting, such as a `-vvv` flag, rather than for end users.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{error_from, report, ErrorWrap};
+///
+/// let error = error_from!("connection refused").wrap("cannot fetch index");
+/// # /*
+/// report::err_debug(&error);
+/// # */
+/// // Error {
+/// //     context: "cannot fetch index",
+/// //     source: "connection refused",
+/// // }
+/// ```
+#[cfg(feature = "error")]
+pub fn err_debug(err: &Error) {
+    let (mut f, _) = writer::output();
+    writeln!(f, "{:#?}", err).expect(STDERR);
+}
+
+/// Report an [`anyhow::Error`] to stderr
+///
+/// The message will consist of a red `error:` title, followed by the
+/// [`Display`] impl for the underlying error.
+///
+/// ## Example
+///
+/// ```
+/// # use anyhow::anyhow;
+/// # use narrate::report;
+/// let error = anyhow!("invalid configuration");
+/// # /*
+/// report::anyhow_err(&error);
+/// # */
+/// // error: invalid configuration
+/// ```
+pub fn anyhow_err(err: &anyhow::Error) {
+    counts::increment(Count::Error);
+    let (mut f, color) = writer::output();
+    format_error_title(err.to_string(), color, &mut f).expect(STDERR);
+    format_error_end(&mut f).expect(STDERR);
+}
+
+/// Report an [`anyhow::Error`] to stderr, printing a list of causes
+///
+/// The message will consist of a red `error:` title, followed by the
+/// [`Display`] impl for the underlying error.
+/// Each subsequent wrapped error will have a plain `cause:` title (see
+/// [`ReportConfig::cause_label`]).
+///
+/// ## Example
+///
+/// Context wrapped error.
+///
+/// ```
+/// # fn parse_config_file(path: &str) -> anyhow::Result<()> {
+/// #   Ok(())
+/// # }
+/// use narrate::report;
+/// use anyhow::{Context, Result};
+///
+/// fn setup_config() -> Result<()> {
+/// # /*
+///     ...
+/// # */
+/// # let path = "";
+///     let user_config = parse_config_file(&path)
+///         .with_context(|| format!("invalid config file: `{}`", &path))?;
+/// # /*
+///     ...
+/// # */
+/// # Ok(())
+/// }
+///
+/// fn main() {
+/// # /*
+///     ...
+/// # */
+///     let res = setup_config().context("invalid configuration");
+///     if let Err(ref err) = res {
+/// # /*
+///         report::anyhow_err_full(err);
+/// # */
+///         // error: invalid configuration
+///         // cause: invalid config file: `config.toml`
+///         // cause: missing key: `author`
+///     }
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+pub fn anyhow_err_full(err: &anyhow::Error) {
+    counts::increment(Count::Error);
+    let (mut f, color) = writer::output();
+    let collapse = collapse_single_cause(err);
+    format_error_title(full_error_title(err, collapse), color, &mut f).expect(STDERR);
+    if !collapse {
+        let cause_color = color.then_some(Severity::Error.color());
+        format_error_causes(err, &[], cause_color, &mut f).expect(STDERR);
+    }
+    format_error_end(&mut f).expect(STDERR);
+}
+
+/// Clear the current line of stderr.
+///
+/// Use this to overwrite transient output, such as a spinner or progress
+/// message. Only has an effect if stderr is directed to a TTY, so logs are
+/// not polluted with control characters.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::report;
+///
+/// eprint!("loading...");
+/// report::clear_line();
+/// eprintln!("done");
+/// ```
+pub fn clear_line() {
+    if atty::is(atty::Stream::Stderr) {
+        let mut f = stderr().lock();
+        write!(f, "\r\x1b[K").expect(STDERR);
+        f.flush().expect(STDERR);
+    }
+}
+
+/// Flush stdout and the report output.
+///
+/// Every report function writes its whole message before returning, and
+/// stderr is not buffered, so reports are never held back. Stdout is line
+/// buffered however, so text printed with [`print!`] may appear after a
+/// report that was made later. Call this before reporting to keep the output
+/// of both streams in order.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{report, Color};
+///
+/// print!("result: 42");
+/// report::flush();
+/// report::status("Finished", "calculation", Color::Green);
+/// ```
+pub fn flush() {
+    io::stdout().flush().expect("writing to stdout");
+    let (mut f, _) = writer::output();
+    f.flush().expect(STDERR);
+}
+
+/// Report a warning to stderr.
+///
+/// The message will consist of a yellow `warning:` title, followed by `msg`.
+///
+/// ## Example
+///
+/// ```
+/// # use narrate::report;
+/// report::warn("config file not found, using defaults");
+/// // warning: config file not found, using defaults
+/// ```
+pub fn warn<M>(msg: M)
+where
+    M: AsRef<str>,
+{
+    diagnostic(Severity::Warning, msg);
+}
+
+/// Report an [`Error`] as a warning to stderr, including its causes and help.
+///
+/// The same as [`err_full`], but with a yellow `warning:` title. Use this for
+/// a non-fatal problem that is itself a wrapped error with useful context.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{error_from, report};
+///
+/// let mut error = error_from!("permission denied").wrap("cannot write cache");
+/// error.add_help("builds will be slower");
+/// report::warn_full(&error);
+/// // warning: cannot write cache
+/// // cause: permission denied
+/// //
+/// // builds will be slower
+/// ```
+#[cfg(feature = "error")]
+pub fn warn_full(err: &Error) {
+    diagnostic_full(Severity::Warning, err);
+}
+
+/// Report a note to stderr.
+///
+/// The message will consist of a blue `note:` title, followed by `msg`.
+///
+/// ## Example
+///
+/// ```
+/// # use narrate::report;
+/// report::note("run with `--verbose` for more output");
+/// // note: run with `--verbose` for more output
+/// ```
+pub fn note<M>(msg: M)
+where
+    M: AsRef<str>,
+{
+    diagnostic(Severity::Note, msg);
+}
+
+/// Report an informational notice to stderr.
+///
+/// The message will consist of a cyan `info:` title, followed by `msg`.
+/// Unlike [`note`], it is not counted by [`summary_counts`].
+///
+/// ## Example
+///
+/// ```
+/// # use narrate::report;
+/// report::info("using cached index from 2 hours ago");
+/// // info: using cached index from 2 hours ago
+/// ```
+pub fn info<M>(msg: M)
+where
+    M: AsRef<str>,
+{
+    diagnostic(Severity::Info, msg);
+}
+
+/// Report a message to stderr with the title and color of `severity`.
+///
+/// [`warn`], [`note`] and [`info`] are shorthands for this. Use it when the
+/// severity is only known at runtime, such as from a lint level. See
+/// [`Severity`] for each title and color.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::report::{self, Severity};
+///
+/// let severity = if cfg!(debug_assertions) {
+///     Severity::Note
+/// } else {
+///     Severity::Warning
+/// };
+/// report::diagnostic(severity, "unused configuration key `color`");
+/// // note: unused configuration key `color`
+/// ```
+pub fn diagnostic<M>(severity: Severity, msg: M)
+where
+    M: AsRef<str>,
+{
+    severity.count();
+    let (mut f, color) = writer::output();
+    format_diagnostic_title(severity, msg.as_ref().to_string(), color, &mut f).expect(STDERR);
+}
+
+/// Report a message to stderr with a custom title, colored for `severity`.
+///
+/// Like [`diagnostic`], but with `title` instead of the severity's own title.
+/// The title gets the color and boldness listed on [`Severity`]. Use
+/// [`report_colored`] to choose the color.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::report::{self, Severity};
+///
+/// report::report(Severity::Warning, "deprecated", "`--fast` is now the default");
+/// // deprecated: `--fast` is now the default
+/// ```
+pub fn report<T, M>(severity: Severity, title: T, msg: M)
+where
+    T: AsRef<str>,
+    M: AsRef<str>,
+{
+    report_colored(severity, title, msg, severity.color());
+}
+
+/// Report a message to stderr with a custom title and color.
+///
+/// The same as [`report`], but the title is colored with `color` instead of
+/// the severity's color. `severity` still decides whether the title is bold
+/// and how the report is counted by [`summary_counts`].
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{
+///     report::{self, Severity},
+///     Color,
+/// };
+///
+/// report::report_colored(Severity::Note, "tip", "use `--jobs` to build faster", Color::Magenta);
+/// // tip: use `--jobs` to build faster
+/// ```
+pub fn report_colored<T, M>(severity: Severity, title: T, msg: M, color: Color)
+where
+    T: AsRef<str>,
+    M: AsRef<str>,
+{
+    severity.count();
+    let (mut f, tty) = writer::output();
+    let color = tty.then_some(color);
+    format_line(title, msg, color, None, severity.bold(), &mut f).expect(STDERR);
+}
+
+/// Report an [`Error`] to stderr with the title and color of `severity`,
+/// including its causes and help.
+///
+/// [`err_full`] and [`warn_full`] are shorthands for this.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{
+///     error_from,
+///     report::{self, Severity},
+///     ErrorWrap,
+/// };
+///
+/// let error = error_from!("file is empty").wrap("cannot read cache");
+/// report::diagnostic_full(Severity::Note, &error);
+/// // note: cannot read cache
+/// // cause: file is empty
+/// ```
+#[cfg(feature = "error")]
+pub fn diagnostic_full(severity: Severity, err: &Error) {
+    severity.count();
+    let (mut f, color) = writer::output();
+    format_diagnostic_full(severity, err, err.help(), color, &mut f).expect(STDERR);
+}
+
+#[cfg(feature = "error")]
+fn format_err_full(err: &Error, color: bool, f: &mut impl Write) -> io::Result<()> {
+    format_diagnostic_full(Severity::Error, err, err.help(), color, f)
+}
+
+#[cfg(feature = "error")]
+fn format_diagnostic_full(
+    severity: Severity,
+    err: &Error,
+    help: Option<&str>,
+    color: bool,
+    f: &mut impl Write,
+) -> io::Result<()> {
+    let collapse = collapse_single_cause(&err.inner);
+    let msg = full_error_title(&err.inner, collapse);
+    format_diagnostic_title(severity, msg, color, f)?;
+    let cause_color = color.then_some(severity.color());
+    format_full_body(err, help, collapse, cause_color, color, f)
+}
+
+/// Title line of a diagnostic. Errors get the tracing span and message style.
+#[inline]
+fn format_diagnostic_title(
+    severity: Severity,
+    msg: String,
+    color: bool,
+    f: &mut impl Write,
+) -> io::Result<()> {
+    match severity {
+        Severity::Error => format_error_title(msg, color, f),
+        _ => {
+            let title_color = color.then_some(severity.color());
+            format_line(severity.title(), msg, title_color, None, severity.bold(), f)
+        }
+    }
+}
+
+/// Everything after the title of a full report: hint, causes, retry delay and
+/// help.
+#[cfg(feature = "error")]
+fn format_full_body(
+    err: &Error,
+    help: Option<&str>,
+    collapse: bool,
+    cause_color: Option<Color>,
+    color: bool,
+    f: &mut impl Write,
+) -> io::Result<()> {
+    format_error_hint(err, color, f)?;
+    if !collapse {
+        format_error_causes(&err.inner, err.tags(), cause_color, f)?;
+    }
+    format_error_retry_after(err, f)?;
+    format_error_help_all(help, f)?;
+    format_error_end(f)
+}
+
+/// Whether an error with exactly one cause should be reported on one line.
+#[inline]
+fn collapse_single_cause(anyhow_err: &anyhow::Error) -> bool {
+    let config = config();
+    config.collapse_single_cause
+        && anyhow_err.chain().len() == 2
+        && !duplicate_first_cause(anyhow_err, &config)
+}
+
+/// Whether the first cause repeats the error message and should be skipped.
+#[inline]
+fn duplicate_first_cause(anyhow_err: &anyhow::Error, config: &ReportConfig) -> bool {
+    config.hide_duplicate_cause
+        && anyhow_err
+            .chain()
+            .nth(1)
+            .is_some_and(|cause| cause.to_string() == anyhow_err.to_string())
+}
+
+#[inline]
+fn full_error_title(anyhow_err: &anyhow::Error, collapse: bool) -> String {
+    match collapse {
+        true => format!("{}: {}", anyhow_err, anyhow_err.root_cause()),
+        false => anyhow_err.to_string(),
+    }
+}
+
+#[inline]
+fn format_error_title(msg: String, color: bool, f: &mut impl Write) -> io::Result<()> {
+    let color = match color {
+        true => Some(Severity::Error.color()),
+        false => None,
+    };
+    #[cfg(feature = "tracing")]
+    let msg = span::prefix(msg);
+    let msg_color = color.and(config().style.error_message);
+    format_line(Severity::Error.title(), msg, color, msg_color, true, f)
+}
+
+#[inline]
+fn format_error_causes(
+    anyhow_err: &anyhow::Error,
+    tags: &[(usize, &'static str)],
+    color: Option<Color>,
+    f: &mut impl Write,
+) -> io::Result<()> {
+    let config = config();
+    let skip = match duplicate_first_cause(anyhow_err, &config) {
+        true => 2,
+        false => 1,
+    };
+    let len = anyhow_err.chain().len();
+    let total = len - skip;
+    let shown = config.max_causes.map_or(total, |max| max.min(total));
+    let mut group = None;
+    for (index, cause) in anyhow_err.chain().enumerate().skip(skip).take(shown) {
+        let tag = cause_tag(tags, len - 1 - index);
+        if let Some(tag) = tag.filter(|_| tag != group) {
+            format_group_header(tag, color, f)?;
+        }
+        group = tag;
+
+        let msg = cause.to_string();
+        let msg_color = color.and(config.style.cause_message);
+        format_line(config.cause_label, msg, color, msg_color, false, f)?;
+    }
+
+    let hidden = total - shown;
+    if hidden > 0 {
+        let ellipsis = if config.ascii { "..." } else { "…" };
+        let noun = if hidden == 1 { "cause" } else { "causes" };
+        writeln!(f, "{} ({} more {})", ellipsis, hidden, noun)?;
+    }
+    Ok(())
+}
+
+/// Tag of the group the cause at `position` from the root cause belongs to.
+#[inline]
+fn cause_tag(tags: &[(usize, &'static str)], position: usize) -> Option<&'static str> {
+    tags.iter()
+        .rev()
+        .find(|&&(start, _)| start <= position)
+        .map(|&(_, tag)| tag)
+}
+
+#[inline]
+fn format_group_header(tag: &str, color: Option<Color>, f: &mut impl Write) -> io::Result<()> {
+    match color {
+        Some(_) => writeln!(f, "{}", format!("[{}]", tag).bold()),
+        None => writeln!(f, "[{}]", tag),
+    }
+}
+
+#[inline]
+#[cfg(feature = "error")]
+fn format_error_hint(err: &Error, color: bool, f: &mut impl Write) -> io::Result<()> {
+    if let Some(hint) = err.hint() {
+        let title = icons::with_icon("hint:", Icon::Hint);
+        for line in config::redact(hint).lines() {
+            match color {
+                true => writeln!(f, "{} {}", title.dimmed(), line.dimmed())?,
+                false => writeln!(f, "{} {}", title, line)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+#[inline]
+#[cfg(feature = "error")]
+fn format_error_retry_after(err: &Error, f: &mut impl Write) -> io::Result<()> {
+    match err.retry_after() {
+        Some(delay) => format_line("retry after", format!("{:?}", delay), None, None, false, f),
+        None => Ok(()),
+    }
+}
+
+#[inline]
+#[cfg(feature = "error")]
+fn format_error_help_all(help: Option<&str>, f: &mut impl Write) -> io::Result<()> {
+    if let Some(help) = help {
+        writeln!(f, "\n{}", config::redact(help))?;
+    }
+    Ok(())
+}
+
+#[inline]
+#[cfg(feature = "error")]
+fn format_error_help(err: &Error, f: &mut impl Write) -> io::Result<()> {
+    if let Some(help) = err.help() {
+        let help = config::redact(help);
+        let help = help.lines().last().unwrap_or_default();
+        writeln!(f, "\n{}", help)?;
+    }
+    Ok(())
+}
+
+#[inline]
+fn format_error_end(f: &mut impl Write) -> io::Result<()> {
+    if config().trailing_newline {
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
+#[inline]
+fn format_line<T, M>(
+    title: T,
+    msg: M,
+    color: Option<Color>,
+    msg_color: Option<Color>,
+    bold: bool,
+    f: &mut impl Write,
+) -> io::Result<()>
+where
+    T: AsRef<str>,
+    M: AsRef<str>,
+{
+    let msg = config::redact(msg.as_ref());
+    match color {
+        Some(color) => {
+            let mut title = title.as_ref().color(color);
+            if bold {
+                title = title.bold();
+            }
+            match msg_color {
+                Some(msg_color) => {
+                    let msg = msg.color(msg_color);
+                    writeln!(f, "{}{} {}", title, ":".white().bold(), msg)
+                }
+                None => writeln!(f, "{}{} {}", title, ":".white().bold(), msg),
+            }
+        }
+        None => writeln!(f, "{}: {}", title.as_ref(), msg),
+    }
+}
+
+#[inline]
+fn format_status<T, M>(
+    indent: usize,
+    title: T,
+    msg: M,
+    color: Option<Color>,
+    f: &mut impl Write,
+) -> io::Result<()>
+where
+    T: AsRef<str>,
+    M: Display,
+{
+    let title = title.as_ref();
+    let pad = 12_usize.saturating_sub(display_width(title));
+    let title = match color {
+        Some(color) => title.color(color).bold(),
+        None => title.normal(),
+    };
+    match config().justify {
+        Justify::Leading => writeln!(f, "{:indent$}{}{:pad$} {}", "", title, "", msg),
+        Justify::Trailing => writeln!(f, "{:indent$}{:pad$}{} {}", "", "", title, msg),
+    }
+}
+
+fn format_percent(title: &str, done: u64, total: u64, start: Option<Instant>, color: Color) {
+    let finished = done >= total;
+    let terminal = writer::is_terminal();
+    if !terminal && !finished {
+        return;
+    }
+
+    let percent = match total {
+        0 => 100,
+        total => u128::from(done.min(total)) * 100 / u128::from(total),
+    };
+    let mut msg = format!("[{:>3}%] {}/{}", percent, done, total);
+    if let Some(start) = start.filter(|_| done > 0 && !finished) {
+        // too far off to show if it does not fit in a `Duration`
+        let ratio = (total - done) as f64 / done as f64;
+        if let Ok(remaining) = Duration::try_from_secs_f64(start.elapsed().as_secs_f64() * ratio) {
+            msg.push_str(&format!(" (eta {})", format_eta(remaining)));
+        }
+    }
+
+    let (mut f, tty) = writer::output();
+    let line = format_status_string(title, msg, tty.then_some(color));
+    match (terminal, finished) {
+        (true, true) => f
+            .write_control("\r\x1b[K")
+            .and_then(|_| writeln!(f, "{}", line)),
+        (true, false) => f
+            .write_control("\r\x1b[K")
+            .and_then(|_| write!(f, "{}", line))
+            .and_then(|_| f.flush()),
+        (false, _) => writeln!(f, "{}", line),
+    }
+    .expect(STDERR);
+}
+
+/// Format a remaining duration for humans, such as `1m 05s`.
+fn format_eta(eta: Duration) -> String {
+    let secs = eta.as_secs();
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m {:02}s", secs / 60, secs % 60),
+        _ => format!("{}h {:02}m", secs / 3600, secs / 60 % 60),
+    }
+}
+
+/// Number of terminal columns `s` takes up.
+#[inline]
+#[cfg(feature = "unicode-width")]
+fn display_width(s: &str) -> usize {
+    unicode_width::UnicodeWidthStr::width(s)
+}
+
+/// Number of terminal columns `s` takes up, assuming one per `char`.
+#[inline]
+#[cfg(not(feature = "unicode-width"))]
+fn display_width(s: &str) -> usize {
+    s.chars().count()
+}
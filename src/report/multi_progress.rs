@@ -0,0 +1,43 @@
+use colored::Color;
+use indicatif::MultiProgress;
+
+#[cfg(feature = "error")]
+use crate::Error;
+
+/// Report a status to stderr above active [`indicatif`] progress bars.
+///
+/// Writing to stderr while progress bars are drawn corrupts them. This hides
+/// the bars of `progress`, prints the [`status`](super::status), then draws
+/// the bars again below it. Only available with the `indicatif` feature.
+///
+/// ## Example
+///
+/// ```
+/// use indicatif::{MultiProgress, ProgressBar};
+/// use narrate::{report, Color};
+///
+/// let progress = MultiProgress::new();
+/// let bar = progress.add(ProgressBar::new(2));
+/// for name in ["narrate", "anyhow"] {
+///     report::status_via(&progress, "Compiling", name, Color::Green);
+///     bar.inc(1);
+/// }
+/// bar.finish();
+/// ```
+pub fn status_via<T, M>(progress: &MultiProgress, title: T, msg: M, color: Color)
+where
+    T: AsRef<str>,
+    M: AsRef<str>,
+{
+    progress.suspend(|| super::status(title, msg, color));
+}
+
+/// Report an [`Error`] to stderr above active [`indicatif`] progress bars.
+///
+/// The same as [`err`](super::err), but hides the bars of `progress` while
+/// printing, like [`status_via`]. Only available with the `indicatif`
+/// feature.
+#[cfg(feature = "error")]
+pub fn err_via(progress: &MultiProgress, err: &Error) {
+    progress.suspend(|| super::err(err));
+}
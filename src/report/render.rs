@@ -0,0 +1,110 @@
+use std::{
+    fmt,
+    io::{self, Write},
+    str,
+};
+
+use super::{counts, Severity};
+use crate::Error;
+
+const STRING: &str = "writing to a String";
+
+/// Render an [`Error`] into any [`fmt::Write`] target, such as a `String`.
+///
+/// The output is the same as [`err_full`](super::err_full), but never
+/// colored.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{error_from, report};
+///
+/// let mut error = error_from!("missing key: `author`").wrap("invalid configuration");
+/// error.add_help("see the docs");
+///
+/// let mut out = String::new();
+/// report::render_err_to_fmt(&error, &mut out).unwrap();
+/// assert_eq!(
+///     "error: invalid configuration\ncause: missing key: `author`\n\nsee the docs\n",
+///     out
+/// );
+/// ```
+pub fn render_err_to_fmt(err: &Error, w: &mut impl fmt::Write) -> fmt::Result {
+    super::format_err_full(err, false, &mut FmtWriter(w)).map_err(|_| fmt::Error)
+}
+
+/// Render every error and warning of a run into one uncolored `String`.
+///
+/// Errors and warnings are listed under `ERRORS` and `WARNINGS` headings, as
+/// [`err_full`](super::err_full) and [`warn_full`](super::warn_full) would
+/// report them, followed by a summary of the counts. Empty sections are left
+/// out. Useful for saving a diagnostics file or support bundle.
+///
+/// Unlike the reporting functions, nothing is added to
+/// [`summary_counts`](super::summary_counts).
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{error_from, report};
+///
+/// let errors = [error_from!("missing key: `author`")];
+/// let warnings = [error_from!("unused key: `color`")];
+///
+/// let out = report::render_all(&errors, &warnings);
+/// assert_eq!(
+///     "ERRORS\n\nerror: missing key: `author`\n\n\
+///      WARNINGS\n\nwarning: unused key: `color`\n\n\
+///      1 error, 1 warning\n",
+///     out
+/// );
+/// ```
+pub fn render_all(errors: &[Error], warnings: &[Error]) -> String {
+    let mut out = String::new();
+    let mut f = FmtWriter(&mut out);
+    render_section("ERRORS", Severity::Error, errors, &mut f).expect(STRING);
+    render_section("WARNINGS", Severity::Warning, warnings, &mut f).expect(STRING);
+
+    let summary = counts::format_summary([(errors.len(), "error"), (warnings.len(), "warning")]);
+    if !summary.is_empty() {
+        writeln!(f, "{}", summary).expect(STRING);
+    }
+    out
+}
+
+fn render_section(
+    heading: &str,
+    severity: Severity,
+    errors: &[Error],
+    f: &mut impl Write,
+) -> io::Result<()> {
+    if errors.is_empty() {
+        return Ok(());
+    }
+    writeln!(f, "{}\n", heading)?;
+    for err in errors {
+        super::format_diagnostic_full(severity, err, err.help(), false, f)?;
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
+/// Adapter to use a [`fmt::Write`] where an [`io::Write`] is expected.
+///
+/// Only valid UTF-8 can be written.
+struct FmtWriter<'a, W>(&'a mut W);
+
+impl<W: fmt::Write> io::Write for FmtWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let s =
+            str::from_utf8(buf).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        self.0
+            .write_str(s)
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
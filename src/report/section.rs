@@ -0,0 +1,100 @@
+use std::{
+    io::{self, Write},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use super::config;
+
+static DEPTH: AtomicUsize = AtomicUsize::new(0);
+
+/// Guard for an indented section of report output.
+///
+/// Created with [`section`]. Output is indented until the guard is dropped.
+#[must_use = "the section ends when the guard is dropped"]
+#[derive(Debug)]
+pub struct Section {
+    _private: (),
+}
+
+/// Indent all following report output by one level.
+///
+/// Each level is indented by [`ReportConfig::indent_width`](super::ReportConfig::indent_width)
+/// spaces. The section ends when the returned [`Section`] is dropped. Sections
+/// can be nested, and apply to report output from every thread.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{error_from, report, Color};
+///
+/// report::status("Building", "workspace", Color::Green);
+/// {
+///     let _section = report::section();
+///     report::status("Compiling", "narrate", Color::Green);
+///     report::err(&error_from!("cannot find crate"));
+/// }
+/// //     Building workspace
+/// //       Compiling narrate
+/// //   error: cannot find crate
+/// ```
+pub fn section() -> Section {
+    DEPTH.fetch_add(1, Ordering::Relaxed);
+    Section { _private: () }
+}
+
+impl Drop for Section {
+    fn drop(&mut self) {
+        DEPTH.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Writer that indents each non-empty line by the current section depth.
+pub(super) struct Indented<W> {
+    inner: W,
+    indent: usize,
+    line_start: bool,
+}
+
+impl<W: Write> Indented<W> {
+    pub(super) fn new(inner: W) -> Self {
+        Self {
+            inner,
+            indent: DEPTH.load(Ordering::Relaxed) * config().indent_width,
+            line_start: true,
+        }
+    }
+
+    /// Number of spaces each line is indented by.
+    pub(super) fn indent(&self) -> usize {
+        self.indent
+    }
+
+    /// Write a terminal control sequence without indenting it.
+    ///
+    /// Sequences that move the cursor to the start of a line, such as `\r`,
+    /// would otherwise remove the indent written before them. The following
+    /// text is still indented if it starts a line.
+    pub(super) fn write_control(&mut self, seq: &str) -> io::Result<()> {
+        self.inner.write_all(seq.as_bytes())
+    }
+}
+
+impl<W: Write> Write for Indented<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.indent == 0 {
+            return self.inner.write(buf);
+        }
+        for line in buf.split_inclusive(|&b| b == b'\n') {
+            if self.line_start && line != b"\n" {
+                write!(self.inner, "{:1$}", "", self.indent)?;
+            }
+            self.inner.write_all(line)?;
+            self.line_start = line.ends_with(b"\n");
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+
+use colored::Color;
+
+use super::{
+    config,
+    counts::{self, Count},
+    icons::{self, Icon},
+    Title,
+};
+
+/// How serious a [`diagnostic`](super::diagnostic) is.
+///
+/// Each severity has its own title and color, which can be changed with a
+/// [`Theme`](super::Theme). By default:
+///
+/// | Severity  | Title     | Color  | Bold |
+/// |-----------|-----------|--------|------|
+/// | `Error`   | `error`   | red    | yes  |
+/// | `Warning` | `warning` | yellow | yes  |
+/// | `Note`    | `note`    | blue   | yes  |
+/// | `Info`    | `info`    | cyan   | yes  |
+/// | `Help`    | `help`    | green  | no   |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Severity {
+    /// A fatal problem.
+    Error,
+    /// A problem that does not stop the program.
+    Warning,
+    /// Extra information.
+    Note,
+    /// A non-fatal notice, such as progress a user may want to know about.
+    Info,
+    /// A suggestion for the user.
+    Help,
+}
+
+impl Severity {
+    /// Title of reports with this severity, with an icon if enabled.
+    pub(super) fn title(self) -> Cow<'static, str> {
+        let word = self.themed().word;
+        match self {
+            Severity::Error => icons::with_icon(word, Icon::Error),
+            Severity::Warning => icons::with_icon(word, Icon::Warning),
+            Severity::Note | Severity::Info | Severity::Help => Cow::Borrowed(word),
+        }
+    }
+
+    /// Color of the title of reports with this severity.
+    pub(super) fn color(self) -> Color {
+        self.themed().color
+    }
+
+    /// Configured title of this severity.
+    fn themed(self) -> Title {
+        let theme = config::config().theme;
+        match self {
+            Severity::Error => theme.error,
+            Severity::Warning => theme.warning,
+            Severity::Note => theme.note,
+            Severity::Info => theme.info,
+            Severity::Help => theme.help,
+        }
+    }
+
+    /// Whether titles of reports with this severity are bold.
+    pub(super) fn bold(self) -> bool {
+        !matches!(self, Severity::Help)
+    }
+
+    /// Count a report with this severity for [`summary_counts`](super::summary_counts).
+    pub(super) fn count(self) {
+        match self {
+            Severity::Error => counts::increment(Count::Error),
+            Severity::Warning => counts::increment(Count::Warning),
+            Severity::Note => counts::increment(Count::Note),
+            Severity::Info | Severity::Help => {}
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt::{self, Write};
+
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Span, Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer, Registry};
+
+/// [`Layer`] that records span fields, so error reports can show them.
+///
+/// Without this layer, reports made inside a span are only prefixed with its
+/// name. Add it to a subscriber built on a [`Registry`] to show the fields of
+/// the span as well, such as `error: fetch{attempt=2}: connection refused`.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::{error_from, report};
+/// use tracing_subscriber::{layer::SubscriberExt, Registry};
+///
+/// let subscriber = Registry::default().with(report::SpanFieldsLayer);
+/// tracing::subscriber::with_default(subscriber, || {
+///     let _span = tracing::info_span!("fetch", attempt = 2).entered();
+///     report::err(&error_from!("connection refused"));
+///     // error: fetch{attempt=2}: connection refused
+/// });
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SpanFieldsLayer;
+
+/// Fields of a span, formatted as `name=value` pairs separated by spaces.
+struct SpanFields(String);
+
+impl<S> Layer<S> for SpanFieldsLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut fields = SpanFields(String::new());
+        attrs.record(&mut fields);
+        span.extensions_mut().insert(fields);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if let Some(fields) = extensions.get_mut::<SpanFields>() {
+            values.record(fields);
+        }
+    }
+}
+
+impl Visit for SpanFields {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        write!(self.0, "{}={:?}", field.name(), value).expect("writing to a String");
+    }
+}
+
+/// Prefix `msg` with the name of the current [`tracing`] span, if any, and
+/// its fields if recorded by [`SpanFieldsLayer`].
+pub(super) fn prefix(msg: String) -> String {
+    let span = Span::current();
+    let Some(metadata) = span.metadata() else {
+        return msg;
+    };
+    match fields(&span).filter(|fields| !fields.is_empty()) {
+        Some(fields) => format!("{}{{{}}}: {}", metadata.name(), fields, msg),
+        None => format!("{}: {}", metadata.name(), msg),
+    }
+}
+
+fn fields(span: &Span) -> Option<String> {
+    span.with_subscriber(|(id, dispatch)| {
+        let registry = dispatch.downcast_ref::<Registry>()?;
+        let span = registry.span(id)?;
+        let extensions = span.extensions();
+        extensions
+            .get::<SpanFields>()
+            .map(|fields| fields.0.clone())
+    })
+    .flatten()
+}
@@ -0,0 +1,49 @@
+use std::fmt::Write;
+
+use syslog::{Facility, Formatter3164};
+
+use super::config::redact;
+use crate::Error;
+
+/// Report an [`Error`] to the local syslog daemon.
+///
+/// The error and each of its causes are joined into a single `err` severity
+/// message, followed by any help text:
+///
+/// ```txt
+/// error: <msg>; cause: <cause>; help: <help>
+/// ```
+///
+/// Returns an error if the syslog daemon cannot be reached.
+///
+/// ## Example
+///
+/// ```no_run
+/// use narrate::{error_from, report};
+/// use narrate::report::Facility;
+///
+/// let error = error_from!("invalid configuration");
+/// report::err_syslog(&error, Facility::LOG_DAEMON).ok();
+/// ```
+pub fn err_syslog(err: &Error, facility: Facility) -> syslog::Result<()> {
+    let formatter = Formatter3164 {
+        facility,
+        ..Default::default()
+    };
+    let mut logger = syslog::unix(formatter)?;
+    logger.err(format_syslog_message(err))
+}
+
+fn format_syslog_message(err: &Error) -> String {
+    let mut msg = format!("error: {}", redact(&err.to_string()));
+    let label = super::config().cause_label;
+    for cause in err.causes() {
+        let cause = cause.to_string();
+        write!(msg, "; {}: {}", label, redact(&cause)).expect("writing to a String");
+    }
+    if let Some(help) = err.help() {
+        let help = redact(help).replace('\n', "; ");
+        write!(msg, "; help: {}", help).expect("writing to a String");
+    }
+    msg
+}
@@ -0,0 +1,112 @@
+use std::{
+    io::{self, Write},
+    sync::{Mutex, PoisonError},
+};
+
+type TeeWriter = Box<dyn Write + Send>;
+
+static TEE: Mutex<Option<TeeWriter>> = Mutex::new(None);
+
+/// Copy all following report output to `writer`.
+///
+/// Reports are still printed to stderr (or the destination set with
+/// [`with_writer`](super::with_writer)) as usual. The copy is never colored,
+/// even when the main output is, so `writer` can be a plain log file.
+///
+/// Replaces any previous tee. Use [`clear_tee`] to stop copying.
+///
+/// ## Example
+///
+/// ```
+/// use std::fs::OpenOptions;
+///
+/// use narrate::{error_from, report};
+///
+/// let log = OpenOptions::new()
+///     .create(true)
+///     .append(true)
+///     .open("diagnostics.log")
+///     .unwrap();
+/// report::set_tee(log);
+/// report::err(&error_from!("cannot reach license server"));
+/// # report::clear_tee();
+/// # std::fs::remove_file("diagnostics.log").unwrap();
+/// ```
+pub fn set_tee<W>(writer: W)
+where
+    W: Write + Send + 'static,
+{
+    *TEE.lock().unwrap_or_else(PoisonError::into_inner) = Some(Box::new(writer));
+}
+
+/// Stop copying report output to the writer set with [`set_tee`].
+pub fn clear_tee() {
+    *TEE.lock().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Whether report output is currently copied to a tee.
+pub(super) fn is_set() -> bool {
+    TEE.lock().unwrap_or_else(PoisonError::into_inner).is_some()
+}
+
+/// Writer that copies everything written to it to the tee, without ANSI
+/// escape sequences.
+pub(super) struct Tee<W> {
+    inner: W,
+    escape: Escape,
+}
+
+/// Position in an ANSI escape sequence, which may be split across writes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Escape {
+    None,
+    Start,
+    Params,
+}
+
+impl<W> Tee<W> {
+    pub(super) fn new(inner: W) -> Self {
+        Self {
+            inner,
+            escape: Escape::None,
+        }
+    }
+
+    fn strip_escapes(&mut self, buf: &[u8]) -> Vec<u8> {
+        let mut plain = Vec::with_capacity(buf.len());
+        for &byte in buf {
+            self.escape = match (self.escape, byte) {
+                (Escape::None, b'\x1b') => Escape::Start,
+                (Escape::None, _) => {
+                    plain.push(byte);
+                    Escape::None
+                }
+                (Escape::Start, b'[') => Escape::Params,
+                (Escape::Start, _) => Escape::None,
+                // a byte in `@`..=`~` ends the sequence
+                (Escape::Params, 0x40..=0x7e) => Escape::None,
+                (Escape::Params, _) => Escape::Params,
+            };
+        }
+        plain
+    }
+}
+
+impl<W: Write> Write for Tee<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write_all(buf)?;
+        let plain = self.strip_escapes(buf);
+        if let Some(tee) = TEE.lock().unwrap_or_else(PoisonError::into_inner).as_mut() {
+            tee.write_all(&plain)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()?;
+        if let Some(tee) = TEE.lock().unwrap_or_else(PoisonError::into_inner).as_mut() {
+            tee.flush()?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+use std::{
+    env,
+    io::{stderr, stdout, Write},
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        PoisonError, RwLock,
+    },
+};
+
+use super::{
+    section::Indented,
+    tee::{self, Tee},
+};
+
+type WriterFactory = Box<dyn Fn() -> Box<dyn Write> + Send + Sync>;
+
+static WRITER: RwLock<Option<WriterFactory>> = RwLock::new(None);
+static COLOR_CHOICE: AtomicU8 = AtomicU8::new(ColorChoice::Auto as u8);
+
+/// Whether report output to stderr or stdout is colored.
+///
+/// Set with [`set_color_choice`], such as from a `--color` flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Always color, even when the output is not a TTY.
+    Always,
+
+    /// Never color.
+    Never,
+
+    /// Color if the output is a TTY, unless overridden by the `NO_COLOR` or
+    /// `CLICOLOR_FORCE` environment variables.
+    #[default]
+    Auto,
+}
+
+/// Standard stream for a report to be printed to.
+///
+/// See [`status_stream`](super::status_stream).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stream {
+    /// Standard output.
+    Stdout,
+
+    /// Standard error, the default for all reports.
+    Stderr,
+}
+
+/// Choose whether report output is colored, matching the semantics of
+/// Cargo's `--color=always|never|auto`.
+///
+/// [`ColorChoice::Always`] and [`ColorChoice::Never`] take precedence over the
+/// `NO_COLOR` and `CLICOLOR_FORCE` environment variables. Output to a
+/// [custom writer](with_writer) is never colored.
+///
+/// This also sets the [`colored`] override, so other text
+/// colored with it follows the same choice.
+///
+/// ## Example
+///
+/// ```
+/// use narrate::report::{self, ColorChoice};
+///
+/// let color = "never"; // e.g. from a `--color` flag
+/// report::set_color_choice(match color {
+///     "always" => ColorChoice::Always,
+///     "never" => ColorChoice::Never,
+///     _ => ColorChoice::Auto,
+/// });
+/// # report::set_color_choice(ColorChoice::Auto);
+/// ```
+pub fn set_color_choice(choice: ColorChoice) {
+    COLOR_CHOICE.store(choice as u8, Ordering::Relaxed);
+    match choice {
+        ColorChoice::Always => colored::control::set_override(true),
+        ColorChoice::Never => colored::control::set_override(false),
+        ColorChoice::Auto => colored::control::unset_override(),
+    }
+}
+
+fn color_choice() -> ColorChoice {
+    match COLOR_CHOICE.load(Ordering::Relaxed) {
+        choice if choice == ColorChoice::Always as u8 => ColorChoice::Always,
+        choice if choice == ColorChoice::Never as u8 => ColorChoice::Never,
+        _ => ColorChoice::Auto,
+    }
+}
+
+/// Send all following report output to writers created by `factory`.
+///
+/// `factory` is called each time a report function prints, so the
+/// destination can change between calls, such as after rotating a log file.
+/// Output to a custom writer is never colored.
+///
+/// Pass [`std::io::stderr`] to return to the default destination.
+///
+/// ## Example
+///
+/// ```
+/// use std::{fs::OpenOptions, io};
+///
+/// use narrate::{report, Color};
+///
+/// report::with_writer(|| -> Box<dyn io::Write> {
+///     match OpenOptions::new().append(true).open("batch.log") {
+///         Ok(file) => Box::new(file),
+///         Err(_) => Box::new(io::stderr()),
+///     }
+/// });
+/// report::status("Processed", "42 records", Color::Green);
+/// # report::with_writer(io::stderr);
+/// ```
+pub fn with_writer<F, W>(factory: F)
+where
+    F: Fn() -> W + Send + Sync + 'static,
+    W: Write + 'static,
+{
+    let factory: WriterFactory = Box::new(move || Box::new(factory()));
+    *WRITER.write().unwrap_or_else(PoisonError::into_inner) = Some(factory);
+}
+
+/// Writer for the next report, and whether it should be colored.
+///
+/// Defaults to stderr, colored according to [`stream_color`]. Output is also
+/// copied to the tee, if one is set.
+pub(super) fn output() -> (Indented<Box<dyn Write>>, bool) {
+    let writer = WRITER.read().unwrap_or_else(PoisonError::into_inner);
+    let (inner, color): (Box<dyn Write>, _) = match writer.as_ref() {
+        Some(factory) => (factory(), false),
+        None => (Box::new(stderr().lock()), stream_color(Stream::Stderr)),
+    };
+    indented(inner, color)
+}
+
+/// Writer for a report to `stream`, and whether it should be colored.
+///
+/// A writer set with [`with_writer`] only replaces stderr, so is not used for
+/// [`Stream::Stdout`].
+pub(super) fn output_to(stream: Stream) -> (Indented<Box<dyn Write>>, bool) {
+    match stream {
+        Stream::Stdout => indented(Box::new(stdout().lock()), stream_color(Stream::Stdout)),
+        Stream::Stderr => output(),
+    }
+}
+
+fn indented(inner: Box<dyn Write>, color: bool) -> (Indented<Box<dyn Write>>, bool) {
+    match tee::is_set() {
+        true => (Indented::new(Box::new(Tee::new(inner))), color),
+        false => (Indented::new(inner), color),
+    }
+}
+
+/// Whether reports are printed to a terminal.
+///
+/// `false` if stderr is redirected, or a writer has been set with
+/// [`with_writer`]. Use this to skip work that only matters to a user
+/// watching, such as progress output or detailed help.
+///
+/// ```
+/// use narrate::report;
+///
+/// if report::is_terminal() {
+///     report::status("Checking", "dependencies", narrate::Color::Green);
+/// }
+/// ```
+pub fn is_terminal() -> bool {
+    let writer = WRITER.read().unwrap_or_else(PoisonError::into_inner);
+    writer.is_none() && atty::is(atty::Stream::Stderr)
+}
+
+/// Width of the terminal, read from `COLUMNS`.
+pub(super) fn columns() -> Option<usize> {
+    env::var("COLUMNS").ok()?.parse().ok()
+}
+
+/// Whether output to `stream` should be colored.
+///
+/// A [`ColorChoice`] other than `Auto` decides. Otherwise `NO_COLOR` disables
+/// color, then `CLICOLOR_FORCE` enables it. If neither is set, color is only
+/// used if `stream` is a TTY.
+fn stream_color(stream: Stream) -> bool {
+    match color_choice() {
+        ColorChoice::Always => return true,
+        ColorChoice::Never => return false,
+        ColorChoice::Auto => {}
+    }
+    if env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+        return false;
+    }
+    if env::var_os("CLICOLOR_FORCE").is_some_and(|value| !value.is_empty() && value != "0") {
+        return true;
+    }
+    match stream {
+        Stream::Stdout => atty::is(atty::Stream::Stdout),
+        Stream::Stderr => atty::is(atty::Stream::Stderr),
+    }
+}
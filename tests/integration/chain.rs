@@ -28,6 +28,122 @@ fn wrapped_nested_error() {
     assert!(chain.next().is_none());
 }
 
+#[test]
+fn causes_skip_top_level_error() {
+    let error = Error::new(TestError::Stub(ErrorStub)).wrap("context");
+    let mut causes = error.causes();
+    assert_eq!(2, causes.len());
+    assert_eq!(
+        TestError::Stub(ErrorStub).to_string(),
+        next_string(&mut causes)
+    );
+    assert_eq!(ErrorStub.to_string(), next_string(&mut causes));
+    assert!(causes.next().is_none());
+}
+
+#[test]
+fn causes_len_excludes_top_level_error() {
+    let e = error();
+    let mut causes = e.causes();
+    assert_eq!(e.chain().len() - 1, causes.len());
+    assert_eq!(3, causes.len());
+    assert_eq!((3, Some(3)), causes.size_hint());
+    causes.next();
+    assert_eq!(2, causes.len());
+    assert_eq!(3, e.causes().rev().count());
+}
+
+#[test]
+fn causes_empty_without_source() {
+    let error = Error::new(ErrorStub);
+    assert_eq!(0, error.causes().count());
+}
+
+#[test]
+fn cause_at_depth() {
+    let e = error();
+    assert_eq!("3", e.cause_at(0).unwrap().to_string());
+    assert_eq!("1", e.cause_at(2).unwrap().to_string());
+    assert_eq!("0", e.cause_at(3).unwrap().to_string());
+    assert!(e.cause_at(4).is_none());
+}
+
+#[test]
+fn chain_with_last_marks_final_cause() {
+    let error = Error::new(TestError::Stub(ErrorStub)).wrap("context");
+    let last: Vec<_> = error.chain_with_last().map(|(last, _)| last).collect();
+    assert_eq!(vec![false, false, true], last);
+}
+
+#[test]
+fn chain_with_last_single_error() {
+    let error = Error::new(ErrorStub);
+    let mut chain = error.chain_with_last();
+    let (last, cause) = chain.next().unwrap();
+    assert!(last);
+    assert_eq!(ErrorStub.to_string(), cause.to_string());
+    assert!(chain.next().is_none());
+}
+
+#[test]
+fn wrap_static_str_context() {
+    let error = Error::new(ErrorStub).wrap("context");
+    let mut chain = error.chain();
+    assert_eq!("context", next_string(&mut chain));
+    assert_eq!(ErrorStub.to_string(), next_string(&mut chain));
+    assert!(chain.next().is_none());
+    assert!(error.is::<&str>());
+}
+
+#[test]
+fn wrap_all_in_order() {
+    let error = Error::new(ErrorStub).wrap_all(vec!["first", "second"]);
+    let mut chain = error.chain();
+    assert_eq!("second", next_string(&mut chain));
+    assert_eq!("first", next_string(&mut chain));
+    assert_eq!(ErrorStub.to_string(), next_string(&mut chain));
+    assert!(chain.next().is_none());
+}
+
+#[test]
+fn wrap_error_keeps_other_chain() {
+    let other = Error::msg("other root").wrap("other top");
+    let error = Error::new(ErrorStub).wrap("context").wrap_error(other);
+    let mut chain = error.chain();
+    assert_eq!("other top", next_string(&mut chain));
+    assert_eq!("other root", next_string(&mut chain));
+    assert_eq!("context", next_string(&mut chain));
+    assert_eq!(ErrorStub.to_string(), next_string(&mut chain));
+    assert!(chain.next().is_none());
+}
+
+#[test]
+fn wrap_error_merges_help_and_hint() {
+    let mut error = Error::new(ErrorStub);
+    error.add_help("inner help");
+    let mut other = Error::msg("other");
+    other.add_help("outer help");
+    other.add_hint("outer hint");
+    let error = error.wrap_error(other);
+    assert_eq!(Some("inner help\nouter help"), error.help());
+    assert_eq!(Some("outer hint"), error.hint());
+}
+
+#[test]
+fn same_chain_ignores_help() {
+    let mut first = error();
+    first.add_help("first help");
+    let mut second = error();
+    second.add_help("second help");
+    assert!(first.same_chain_as(&second));
+}
+
+#[test]
+fn different_chain() {
+    assert!(!error().same_chain_as(&error().wrap(4)));
+    assert!(!error().same_chain_as(&Error::new(ErrorStub)));
+}
+
 fn next_string(chain: &mut Chain) -> String {
     chain.next().expect("error source").to_string()
 }
@@ -98,6 +214,12 @@ fn clone() {
     assert!(chain.next_back().is_none());
 }
 
+#[test]
+fn context_count_includes_top_level_error() {
+    assert_eq!(1, Error::new(ErrorStub).context_count());
+    assert_eq!(4, error().context_count());
+}
+
 fn error() -> Error {
     narrate::error_from!({ 0 }).wrap(1).wrap(2).wrap(3)
 }
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{io::ErrorKind, path::PathBuf, time::Duration};
 
 use narrate::{CliError, ExitCode};
 
@@ -7,12 +7,78 @@ fn outputs() {
     cli_error_array().iter().for_each(assert_error_msg_and_code);
 }
 
+narrate::define_cli_error! {
+    /// Test errors with custom exit codes
+    enum CustomError {
+        /// First error
+        First => (3, "first error"),
+        Second => (exitcode::UNAVAILABLE, "second error"),
+    }
+}
+
+#[test]
+fn define_cli_error_outputs() {
+    assert_eq!("first error", CustomError::First.to_string());
+    assert_eq!(3, CustomError::First.exit_code());
+    assert_eq!("second error", CustomError::Second.to_string());
+    assert_eq!(exitcode::UNAVAILABLE, i32::from(CustomError::Second));
+}
+
+#[test]
+fn define_cli_error_into_error_keeps_code() {
+    let error = CustomError::First.into_error();
+    assert_eq!("first error", error.to_string());
+    assert_eq!(3, error.exit_code());
+
+    let error = error.wrap("outer context");
+    assert_eq!(3, error.exit_code());
+}
+
 #[test]
 fn impl_std_error() {
     fn assert_std_error(_e: impl std::error::Error) {}
     assert_std_error(CliError::Config);
 }
 
+#[test]
+fn partial_failure_single() {
+    let error = CliError::PartialFailure {
+        succeeded: 3,
+        failed: 1,
+    };
+    assert_eq!("completed with 1 failure (3 succeeded)", error.to_string());
+}
+
+#[test]
+fn from_io_specific_variants() {
+    let path = PathBuf::from("/tmp/file");
+    assert_eq!(
+        CliError::InputFileNotFound(path.clone()),
+        CliError::from_io(ErrorKind::NotFound, &path)
+    );
+    assert_eq!(
+        CliError::OperationPermission(String::from("/tmp/file")),
+        CliError::from_io(ErrorKind::PermissionDenied, &path)
+    );
+    assert_eq!(
+        CliError::AlreadyExists(path.clone()),
+        CliError::from_io(ErrorKind::AlreadyExists, &path)
+    );
+}
+
+#[test]
+fn from_io_other_kind_kept() {
+    let error = CliError::from_io(ErrorKind::TimedOut, "/tmp/file");
+    assert_eq!(
+        CliError::Io {
+            kind: ErrorKind::TimedOut,
+            path: PathBuf::from("/tmp/file"),
+        },
+        error
+    );
+    assert_eq!(exitcode::TEMPFAIL, error.exit_code());
+}
+
 struct ErrorTest {
     error: CliError,
     msg: String,
@@ -39,19 +105,37 @@ fn assert_error_msg_and_code(error_test: &ErrorTest) {
     );
 }
 
-fn cli_error_array() -> [ErrorTest; 15] {
+fn cli_error_array() -> [ErrorTest; 23] {
     let path_buf = PathBuf::from("path");
     [
+        ErrorTest {
+            error: CliError::AlreadyExists(path_buf.clone()),
+            msg: format!("already exists: {}", path_buf.display()),
+            code: exitcode::CANTCREAT,
+        },
         ErrorTest {
             error: CliError::Config,
             msg: "invalid configuration".into(),
             code: exitcode::CONFIG,
         },
+        ErrorTest {
+            error: CliError::Conflict("file already exists".into()),
+            msg: "conflict: file already exists".into(),
+            code: exitcode::CANTCREAT,
+        },
         ErrorTest {
             error: CliError::CreateFile(path_buf.clone()),
             msg: format!("cannot create file: {}", path_buf.display()),
             code: exitcode::CANTCREAT,
         },
+        ErrorTest {
+            error: CliError::Custom {
+                code: 42,
+                message: "custom error".into(),
+            },
+            msg: "custom error".into(),
+            code: 42,
+        },
         ErrorTest {
             error: CliError::InputData,
             msg: "invalid input data".into(),
@@ -62,6 +146,30 @@ fn cli_error_array() -> [ErrorTest; 15] {
             msg: format!("file not found: {}", path_buf.display()),
             code: exitcode::NOINPUT,
         },
+        ErrorTest {
+            error: CliError::Io {
+                kind: ErrorKind::NotFound,
+                path: path_buf.clone(),
+            },
+            msg: format!("entity not found: {}", path_buf.display()),
+            code: exitcode::NOINPUT,
+        },
+        ErrorTest {
+            error: CliError::Io {
+                kind: ErrorKind::PermissionDenied,
+                path: path_buf.clone(),
+            },
+            msg: format!("permission denied: {}", path_buf.display()),
+            code: exitcode::NOPERM,
+        },
+        ErrorTest {
+            error: CliError::Io {
+                kind: ErrorKind::UnexpectedEof,
+                path: path_buf.clone(),
+            },
+            msg: format!("unexpected end of file: {}", path_buf.display()),
+            code: exitcode::IOERR,
+        },
         ErrorTest {
             error: CliError::NoUser("username".into()),
             msg: "user not found: username".into(),
@@ -87,6 +195,14 @@ fn cli_error_array() -> [ErrorTest; 15] {
             msg: format!("system file not found: {}", path_buf.display()),
             code: exitcode::OSFILE,
         },
+        ErrorTest {
+            error: CliError::PartialFailure {
+                succeeded: 8,
+                failed: 2,
+            },
+            msg: "completed with 2 failures (8 succeeded)".into(),
+            code: exitcode::DATAERR,
+        },
         ErrorTest {
             error: CliError::ReadFile(path_buf.clone()),
             msg: format!("cannot read file: {}", path_buf.display()),
@@ -107,6 +223,11 @@ fn cli_error_array() -> [ErrorTest; 15] {
             msg: "temporary failure".into(),
             code: exitcode::TEMPFAIL,
         },
+        ErrorTest {
+            error: CliError::Timeout(Duration::from_secs(30)),
+            msg: "operation timed out after 30s".into(),
+            code: exitcode::TEMPFAIL,
+        },
         ErrorTest {
             error: CliError::Usage,
             msg: "incorrect usage".into(),
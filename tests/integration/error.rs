@@ -1,5 +1,7 @@
+use std::borrow::Cow;
+
 use anyhow::anyhow;
-use narrate::{CliError, Error};
+use narrate::{CliError, Error, ErrorElement};
 
 use crate::util::{assert_error, test_error_stub, ErrorStub, ExpectedErr, TestError};
 
@@ -42,6 +44,138 @@ fn is_not_original() {
     assert!(!error.is::<CliError>());
 }
 
+#[test]
+fn bail_help_static_help() {
+    fn run() -> narrate::Result<()> {
+        narrate::bail_help!("error message"; "help message");
+    }
+    let error = run().unwrap_err();
+    assert_eq!("error message", error.to_string());
+    assert_eq!(Some("help message"), error.help());
+}
+
+#[test]
+fn bail_help_formatted_error_and_help() {
+    fn run(name: &str) -> narrate::Result<()> {
+        narrate::bail_help!("cannot find {}", name; "did you mean {}?", name.to_uppercase());
+    }
+    let error = run("path").unwrap_err();
+    assert_eq!("cannot find path", error.to_string());
+    assert_eq!(Some("did you mean PATH?"), error.help());
+}
+
+#[test]
+fn bail_help_inline_captured_args() {
+    fn run(path: &str) -> narrate::Result<()> {
+        narrate::bail_help!("cannot read {path}"; "check {path} exists");
+    }
+    let error = run("data.csv").unwrap_err();
+    assert_eq!("cannot read data.csv", error.to_string());
+    assert_eq!(Some("check data.csv exists"), error.help());
+}
+
+#[test]
+fn bail_help_error_expr() {
+    fn run() -> narrate::Result<()> {
+        narrate::bail_help!(CliError::Config; "see the docs");
+    }
+    let error = run().unwrap_err();
+    assert!(error.is::<CliError>());
+    assert_eq!(Some("see the docs"), error.help());
+}
+
+#[test]
+fn ensure_passes() {
+    fn check() -> narrate::Result<()> {
+        narrate::ensure!(1 + 1 == 2, "maths is broken");
+        Ok(())
+    }
+    assert!(check().is_ok());
+}
+
+#[test]
+fn ensure_fails_with_each_arm() {
+    fn literal() -> narrate::Result<()> {
+        narrate::ensure!(false, "literal message");
+        Ok(())
+    }
+    fn expr() -> narrate::Result<()> {
+        narrate::ensure!(false, CliError::Config);
+        Ok(())
+    }
+    fn format(n: usize) -> narrate::Result<()> {
+        narrate::ensure!(n < 3, "too many: {}", n);
+        Ok(())
+    }
+    assert_eq!("literal message", literal().unwrap_err().to_string());
+    assert!(expr().unwrap_err().is::<CliError>());
+    assert_eq!("too many: 5", format(5).unwrap_err().to_string());
+}
+
+#[test]
+fn is_any_of_matches_listed_type() {
+    let error = Error::new(ErrorStub);
+    assert!(narrate::is_any_of!(error, CliError, ErrorStub));
+}
+
+#[test]
+fn is_any_of_no_listed_type() {
+    let error = Error::new(ErrorStub);
+    assert!(!narrate::is_any_of!(&error, CliError, std::io::Error));
+}
+
+#[test]
+fn is_any_of_wrapped_error() {
+    let error = Error::new(ErrorStub).wrap("context");
+    assert!(narrate::is_any_of!(error, std::io::Error, ErrorStub,));
+}
+
+#[test]
+fn downcast_any_attached_value() {
+    let error = Error::new(ErrorStub).with_any(Box::new(42_u32));
+    assert_eq!(Some(&42), error.downcast_any::<u32>());
+}
+
+#[test]
+fn downcast_any_wrong_type() {
+    let error = Error::new(ErrorStub).with_any(Box::new(42_u32));
+    assert!(error.downcast_any::<String>().is_none());
+}
+
+#[test]
+fn downcast_any_without_value() {
+    let error = Error::new(ErrorStub);
+    assert!(error.downcast_any::<u32>().is_none());
+}
+
+#[test]
+fn downcast_any_kept_after_wrap() {
+    let error = Error::new(ErrorStub)
+        .with_any(Box::new("plugin"))
+        .wrap("context");
+    assert_eq!(Some(&"plugin"), error.downcast_any::<&str>());
+}
+
+#[test]
+fn find_ref_in_source() {
+    let error = Error::new(TestError::Stub(ErrorStub));
+    assert!(error.downcast_ref::<ErrorStub>().is_none());
+    assert_eq!(Some(&ErrorStub), error.find_ref::<ErrorStub>());
+}
+
+#[test]
+fn find_ref_missing() {
+    let error = Error::new(ErrorStub).wrap("context");
+    assert!(error.find_ref::<CliError>().is_none());
+}
+
+#[test]
+fn downcast_mut_wrapped_error() {
+    let mut error = Error::new(CliError::Config).wrap("context");
+    *error.downcast_mut::<CliError>().unwrap() = CliError::Usage;
+    assert_eq!(Some(&CliError::Usage), error.find_ref::<CliError>());
+}
+
 #[test]
 fn downcast_to_original() {
     let error = Error::new(ErrorStub);
@@ -116,6 +250,21 @@ fn add_help_twice() {
     assert_error(&ExpectedErr::new_with_help(ErrorStub, &combined), error);
 }
 
+#[test]
+fn add_help_if_true() {
+    let help = "help message";
+    let mut error = Error::new(ErrorStub);
+    error.add_help_if(true, help);
+    assert_error(&ExpectedErr::new_with_help(ErrorStub, help), error);
+}
+
+#[test]
+fn add_help_if_false() {
+    let mut error = Error::new(ErrorStub);
+    error.add_help_if(false, "help message");
+    assert_error(&ExpectedErr::new(ErrorStub), error);
+}
+
 #[test]
 fn add_help_with() {
     let msg = "help";
@@ -134,3 +283,371 @@ fn add_help_with_twice() {
     let combined = format!("{}\n{}", help_1, help_2);
     assert_error(&ExpectedErr::new_with_help(ErrorStub, &combined), error);
 }
+
+#[test]
+fn ensure_help_without_help() {
+    let mut error = Error::new(ErrorStub);
+    error.ensure_help("default help");
+    assert_error(
+        &ExpectedErr::new_with_help(ErrorStub, "default help"),
+        error,
+    );
+}
+
+#[test]
+fn ensure_help_keeps_existing_help() {
+    let mut error = Error::new(ErrorStub);
+    error.add_help("help message");
+    error.ensure_help("default help");
+    assert_error(
+        &ExpectedErr::new_with_help(ErrorStub, "help message"),
+        error,
+    );
+}
+
+#[test]
+fn add_help_owned_after_static() {
+    let mut error = Error::new(ErrorStub);
+    error.add_help("first help");
+    error.add_help_owned(String::from("second help"));
+    assert_error(
+        &ExpectedErr::new_with_help(ErrorStub, "first help\nsecond help"),
+        error,
+    );
+}
+
+#[test]
+fn with_help_owned_and_static() {
+    let error = Error::new(ErrorStub)
+        .with_help(String::from("first help"))
+        .with_help("second help");
+    assert_error(
+        &ExpectedErr::new_with_help(ErrorStub, "first help\nsecond help"),
+        error,
+    );
+}
+
+#[test]
+fn elements_causes_then_help() {
+    let mut error = Error::new(ErrorStub).wrap("context");
+    error.add_help("help message");
+    error.add_hint("hint");
+    let elements: Vec<String> = error
+        .elements()
+        .map(|element| match element {
+            ErrorElement::Cause(cause) => format!("cause {}", cause),
+            ErrorElement::Hint(hint) => format!("hint {}", hint),
+            ErrorElement::Help(help) => format!("help {}", help),
+            _ => unreachable!(),
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            "cause context",
+            "hint hint",
+            "cause ErrorStub",
+            "help help message"
+        ],
+        elements
+    );
+}
+
+#[test]
+fn elements_without_help() {
+    let error = Error::new(ErrorStub);
+    let mut elements = error.elements();
+    assert!(matches!(elements.next(), Some(ErrorElement::Cause(_))));
+    assert!(elements.next().is_none());
+}
+
+#[test]
+fn debug_lists_causes_and_help() {
+    let mut error = Error::new(ErrorStub).wrap("context");
+    error.add_help("help message");
+    assert_eq!(
+        "context\nCause: ErrorStub\n\nhelp message",
+        format!("{:?}", error)
+    );
+}
+
+#[test]
+fn send_across_threads() {
+    let error = Error::new(ErrorStub).wrap("context");
+    let error = std::thread::spawn(move || error).join().unwrap();
+    assert_eq!("context", error.to_string());
+}
+
+#[test]
+#[cfg(unix)]
+fn from_exit_status() {
+    use std::{os::unix::process::ExitStatusExt, process::ExitStatus};
+
+    use narrate::ExitCode;
+
+    let error = Error::from_exit_status(ExitStatus::from_raw(128 << 8), "git");
+    assert_eq!("command 'git' failed with exit code 128", error.to_string());
+    assert_eq!(128, error.exit_code());
+    assert_eq!(128, error.wrap("cannot pull").exit_code());
+}
+
+#[test]
+#[cfg(unix)]
+fn from_exit_status_signal() {
+    use std::{os::unix::process::ExitStatusExt, process::ExitStatus};
+
+    use narrate::ExitCode;
+
+    let error = Error::from_exit_status(ExitStatus::from_raw(9), "git");
+    assert_eq!(
+        "command 'git' was terminated by a signal",
+        error.to_string()
+    );
+    assert_eq!(exitcode::SOFTWARE, error.exit_code());
+}
+
+#[test]
+fn from_code() {
+    use narrate::ExitCode;
+
+    let error = Error::from_code(75);
+    assert_eq!("process exited with code 75", error.to_string());
+    assert_eq!(75, error.exit_code());
+    assert_eq!(75, error.wrap("cannot sync").exit_code());
+}
+
+#[test]
+fn type_name() {
+    let error = Error::new(ErrorStub).wrap("context");
+    assert_eq!(std::any::type_name::<ErrorStub>(), error.type_name());
+
+    let error: Error = CliError::Config.into();
+    assert_eq!(std::any::type_name::<CliError>(), error.type_name());
+
+    let error = Error::msg("message");
+    assert_eq!("&str", error.type_name());
+
+    let error = Error::from_anyhow(anyhow!("message"));
+    assert_eq!(std::any::type_name::<anyhow::Error>(), error.type_name());
+}
+
+#[test]
+fn location_of_constructor() {
+    let (error, line) = (Error::msg("message"), line!());
+    let location = error.location().expect("location recorded");
+    assert_eq!(file!(), location.file());
+    assert_eq!(line, location.line());
+}
+
+#[test]
+fn location_of_macro() {
+    let (error, line) = (narrate::error_from!("message"), line!());
+    assert_eq!(line, error.location().unwrap().line());
+}
+
+#[test]
+fn location_of_question_mark() {
+    fn fails() -> narrate::Result<()> {
+        Err(ErrorStub)?
+    }
+    let line = line!() - 2;
+    assert_eq!(line, fails().unwrap_err().location().unwrap().line());
+}
+
+#[test]
+fn location_kept_by_wrap() {
+    let (error, line) = (Error::new(ErrorStub), line!());
+    let error = error.wrap("context");
+    assert_eq!(line, error.location().unwrap().line());
+}
+
+#[test]
+fn location_in_alternate_debug() {
+    let (error, line) = (Error::new(ErrorStub), line!());
+    let debug = format!("{:#?}", error);
+    let expected = format!("\n\nat {}:{}:", file!(), line);
+    assert!(debug.contains(&expected), "{}", debug);
+}
+
+#[test]
+fn has_help() {
+    let mut error = Error::new(ErrorStub);
+    assert!(!error.has_help());
+    error.add_help("help");
+    assert!(error.has_help());
+}
+
+#[test]
+fn help_owned_outlives_error() {
+    let mut error = Error::new(ErrorStub);
+    assert_eq!(None, error.help_owned());
+    error.add_help("first");
+    assert!(matches!(error.help_owned(), Some(Cow::Borrowed("first"))));
+    error.add_help_with(|| "second");
+    let help = error.help_owned();
+    drop(error);
+    assert_eq!(Some("first\nsecond"), help.as_deref());
+}
+
+#[test]
+fn display_with_help() {
+    let mut error = Error::new(ErrorStub).wrap("context");
+    assert_eq!("context", error.display_with_help().to_string());
+    error.add_help("first");
+    error.add_help("second");
+    assert_eq!(
+        "context\n\nfirst\nsecond",
+        error.display_with_help().to_string()
+    );
+}
+
+#[test]
+fn help_is() {
+    let mut error = Error::new(ErrorStub);
+    assert!(!error.help_is(""));
+    error.add_help("first");
+    assert!(error.help_is("first"));
+    error.add_help_with(|| "second");
+    assert!(error.help_is("first\nsecond"));
+    assert!(!error.help_is("second"));
+}
+
+#[test]
+fn add_hint_twice() {
+    let mut error = Error::new(ErrorStub);
+    assert_eq!(None, error.hint());
+    error.add_hint("first");
+    error.add_hint("second");
+    assert_eq!(Some("first\nsecond"), error.hint());
+    assert_eq!(None, error.help());
+}
+
+#[test]
+fn wrap_keeps_hint() {
+    let mut error = Error::new(ErrorStub);
+    error.add_hint("hint");
+    let error = error.wrap("context");
+    assert_eq!(Some("hint"), error.hint());
+}
+
+#[test]
+fn cli_error() {
+    let error = Error::new(CliError::Config);
+    assert_eq!(Some(&CliError::Config), error.cli_error());
+    assert!(Error::new(ErrorStub).cli_error().is_none());
+}
+
+#[test]
+fn cli_error_mut() {
+    let mut error = Error::new(CliError::NoHost("host".into()));
+    if let Some(CliError::NoHost(host)) = error.cli_error_mut() {
+        host.push_str(".local");
+    }
+    assert_eq!(
+        CliError::NoHost("host.local".into()).to_string(),
+        error.to_string()
+    );
+    assert!(Error::new(ErrorStub).cli_error_mut().is_none());
+}
+
+#[test]
+fn freeze_exit_code() {
+    use narrate::ExitCode;
+
+    let mut error = Error::new(CliError::Config).wrap("context");
+    error.freeze_exit_code();
+    if let Some(cli_error) = error.cli_error_mut() {
+        *cli_error = CliError::Usage;
+    }
+    assert_eq!(exitcode::CONFIG, error.exit_code());
+    assert_eq!(exitcode::CONFIG, error.wrap("outer").exit_code());
+}
+
+#[test]
+fn help_line_count() {
+    let mut error = Error::new(ErrorStub);
+    assert_eq!(0, error.help_line_count());
+    error.add_help("first");
+    assert_eq!(1, error.help_line_count());
+    error.add_help_with(|| "second\nthird");
+    assert_eq!(3, error.help_line_count());
+}
+
+#[test]
+fn help_line_count_empty_help() {
+    let mut error = Error::new(ErrorStub);
+    error.add_help("");
+    assert!(error.has_help());
+    assert_eq!(0, error.help_line_count());
+}
+
+#[test]
+fn help_lines_none() {
+    let error = Error::new(ErrorStub);
+    assert_eq!(0, error.help_lines().count());
+}
+
+#[test]
+fn help_lines_one() {
+    let mut error = Error::new(ErrorStub);
+    error.add_help("only");
+    assert_eq!(vec!["only"], error.help_lines().collect::<Vec<_>>());
+}
+
+#[test]
+fn help_lines_stacked_in_order() {
+    let mut error = Error::new(ErrorStub);
+    error.add_help("first");
+    error.add_help_with(|| "second\nthird");
+    let lines: Vec<_> = error.help_lines().collect();
+    assert_eq!(vec!["first", "second", "third"], lines);
+}
+
+#[test]
+fn into_anyhow_keep_help() {
+    let mut error = Error::new(ErrorStub).wrap("context");
+    error.add_help("help message");
+    let any_err = error.into_anyhow_keep_help();
+    let chain: Vec<_> = any_err.chain().map(|cause| cause.to_string()).collect();
+    assert_eq!(vec!["help message", "context", "ErrorStub"], chain);
+}
+
+#[test]
+fn into_anyhow_keep_help_without_help() {
+    let any_err = Error::new(ErrorStub).into_anyhow_keep_help();
+    assert_eq!(1, any_err.chain().count());
+    assert!(any_err.is::<ErrorStub>());
+}
+
+#[test]
+fn retry_after() {
+    let delay = std::time::Duration::from_secs(30);
+    let error = Error::new(ErrorStub);
+    assert_eq!(None, error.retry_after());
+    let error = error.with_retry_after(delay).wrap("context");
+    assert_eq!(Some(delay), error.retry_after());
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serialize() {
+    let mut error = Error::new(ErrorStub).wrap("context");
+    error.add_help("help message");
+    let expected = serde_json::json!({
+        "message": "context",
+        "causes": [ErrorStub.to_string()],
+        "help": "help message",
+    });
+    assert_eq!(expected, serde_json::to_value(&error).unwrap());
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serialize_without_help() {
+    let error = Error::msg("message");
+    let expected = serde_json::json!({
+        "message": "message",
+        "causes": [],
+        "help": null,
+    });
+    assert_eq!(expected, serde_json::to_value(&error).unwrap());
+}
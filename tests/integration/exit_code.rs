@@ -62,3 +62,115 @@ fn narrate_result_wrapping_cli_error() {
     let err = error_stub_res().wrap(CliError::Config).unwrap_err();
     assert_eq!(exitcode::CONFIG, err.exit_code());
 }
+
+#[test]
+fn cached_code_updated_after_wrap() {
+    let err = error_from!("err msg");
+    assert_eq!(exitcode::SOFTWARE, err.exit_code());
+    let err = err.wrap(CliError::Config);
+    assert_eq!(exitcode::CONFIG, err.exit_code());
+}
+
+#[test]
+fn cached_code_updated_after_downcast_mut() {
+    let mut err = narrate::Error::new(CliError::Config);
+    assert_eq!(exitcode::CONFIG, err.exit_code());
+    *err.downcast_mut::<CliError>().unwrap() = CliError::Usage;
+    assert_eq!(exitcode::USAGE, err.exit_code());
+}
+
+#[test]
+fn explicit_code_before_cli_error() {
+    let err = narrate::Error::from_code(3).wrap(CliError::Config);
+    assert_eq!(3, err.exit_code());
+}
+
+#[test]
+fn narrate_wrapped_custom_cli_error() {
+    let err = narrate::Error::new(CliError::Custom {
+        code: 42,
+        message: "custom error".into(),
+    })
+    .wrap("context");
+    assert_eq!(42, err.exit_code());
+}
+
+#[test]
+fn wrap_error_keeps_cli_error_code() {
+    let other = error_from!(CliError::Config).wrap("cannot load config");
+    let err = error_from!(CliError::Usage).wrap_error(other);
+    assert_eq!(exitcode::CONFIG, err.exit_code());
+}
+
+#[test]
+fn wrap_error_without_code_keeps_own() {
+    let err = error_from!(CliError::Usage).wrap_error(error_from!("other"));
+    assert_eq!(exitcode::USAGE, err.exit_code());
+}
+
+#[test]
+fn with_exit_code() {
+    let err = error_from!("err msg").with_exit_code(3);
+    assert_eq!(3, err.exit_code());
+}
+
+#[test]
+fn with_exit_code_before_cli_error() {
+    let err = error_from!(CliError::Config)
+        .with_exit_code(3)
+        .wrap(CliError::Usage);
+    assert_eq!(3, err.exit_code());
+}
+
+mod mapper {
+    use std::process::Command;
+
+    // the mapper is global, so each test sets it in its own process
+    const MAPPER_TEST_BIN: &str = env!("CARGO_BIN_EXE_exit_code_mapper_test");
+
+    fn exit_code(args: &[&str]) -> (Option<i32>, String) {
+        let output = Command::new(MAPPER_TEST_BIN)
+            .args(args)
+            .output()
+            .expect("failed to run exit_code_mapper_test");
+        (
+            output.status.code(),
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        )
+    }
+
+    #[test]
+    fn default_without_mapper() {
+        assert_eq!((Some(exitcode::CONFIG), String::new()), exit_code(&[]));
+    }
+
+    #[test]
+    fn mapper_overrides_code() {
+        assert_eq!((Some(42), String::new()), exit_code(&["-m"]));
+    }
+
+    #[test]
+    fn mapper_used_in_error_chain() {
+        assert_eq!((Some(42), String::new()), exit_code(&["-m", "-w"]));
+    }
+
+    #[test]
+    fn mapper_used_after_exit_code_computed() {
+        assert_eq!((Some(42), String::new()), exit_code(&["-c", "-m", "-w"]));
+    }
+
+    #[test]
+    fn exit_code_computed_before_mapper() {
+        assert_eq!(
+            (Some(exitcode::CONFIG), String::new()),
+            exit_code(&["-c", "-w"])
+        );
+    }
+
+    #[test]
+    fn mapper_set_once() {
+        let (code, stderr) = exit_code(&["-m", "-m"]);
+        assert_eq!(Some(42), code);
+        assert_eq!("mapper already set\n", stderr);
+    }
+}
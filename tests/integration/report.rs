@@ -6,8 +6,15 @@ use std::{
 use narrate::{error_from, Error};
 
 const STATUS_TEST_BIN: &str = env!("CARGO_BIN_EXE_status_test");
+const WARN_TEST_BIN: &str = env!("CARGO_BIN_EXE_report_warn_test");
+const SUMMARY_TEST_BIN: &str = env!("CARGO_BIN_EXE_report_summary_test");
+const PERCENT_TEST_BIN: &str = env!("CARGO_BIN_EXE_report_percent_test");
+const BANNER_TEST_BIN: &str = env!("CARGO_BIN_EXE_report_banner_test");
+const SEVERITY_TEST_BIN: &str = env!("CARGO_BIN_EXE_report_severity_test");
+const GITHUB_TEST_BIN: &str = env!("CARGO_BIN_EXE_report_github_test");
 const ERR_TEST_BIN: &str = env!("CARGO_BIN_EXE_report_err_test");
 const ERR_FULL_TEST_BIN: &str = env!("CARGO_BIN_EXE_report_err_full_test");
+const ERR_DEBUG_TEST_BIN: &str = env!("CARGO_BIN_EXE_report_err_debug_test");
 const ANYHOW_ERR_TEST_BIN: &str = env!("CARGO_BIN_EXE_report_anyhow_err_test");
 const ANYHOW_ERR_FULL_TEST_BIN: &str = env!("CARGO_BIN_EXE_report_anyhow_err_full_test");
 
@@ -20,6 +27,834 @@ fn status_output_to_stderr() {
     assert_stderr(&expected, &output);
 }
 
+#[test]
+fn status_skipped() {
+    let output = test_bin(STATUS_TEST_BIN, &["-s", "narrate"]);
+    let expected = format!("{:>12} {}\n", "Skipped", "narrate");
+    assert_stderr(&expected, &output);
+}
+
+#[test]
+fn status_done() {
+    let output = test_bin(STATUS_TEST_BIN, &["-D", "building docs"]);
+    let expected = format!("{:>12} {}\n", "Done", "building docs");
+    assert_stderr(&expected, &output);
+}
+
+#[test]
+fn status_failed() {
+    let output = test_bin(STATUS_TEST_BIN, &["-F", "uploading docs"]);
+    let expected = format!("{:>12} {}\n", "Failed", "uploading docs");
+    assert_stderr(&expected, &output);
+}
+
+#[test]
+fn status_stdout() {
+    let output = test_bin(STATUS_TEST_BIN, &["-o", "hi", "world", "green"]);
+    let expected = format!("{:>12} {}\n", "hi", "world");
+    assert_stdout(&expected, &output);
+    assert_stderr("", &output);
+}
+
+#[test]
+fn status_stream_stdout() {
+    let output = test_bin(STATUS_TEST_BIN, &["-t", "stdout", "hi", "world", "green"]);
+    let expected = format!("{:>12} {}\n", "hi", "world");
+    assert_stdout(&expected, &output);
+    assert_stderr("", &output);
+}
+
+#[test]
+fn status_stream_stderr() {
+    let output = test_bin(STATUS_TEST_BIN, &["-t", "stderr", "hi", "world", "green"]);
+    let expected = format!("{:>12} {}\n", "hi", "world");
+    assert_stderr(&expected, &output);
+    assert_stdout("", &output);
+}
+
+#[test]
+fn status_stream_stderr_with_writer() {
+    let args = ["--stdout", "-t", "stderr", "hi", "world", "green"];
+    let output = test_bin(STATUS_TEST_BIN, &args);
+    let expected = format!("{:>12} {}\n", "hi", "world");
+    assert_stdout(&expected, &output);
+    assert_stderr("", &output);
+}
+
+#[test]
+fn status_with_writer() {
+    let output = test_bin(STATUS_TEST_BIN, &["--stdout", "hi", "world", "green"]);
+    let expected = format!("{:>12} {}\n", "hi", "world");
+    assert_stdout(&expected, &output);
+    assert_stderr("", &output);
+}
+
+#[test]
+fn err_full_with_writer() {
+    let output = test_bin(
+        ERR_FULL_TEST_BIN,
+        &["inner error", "outer error", "--stdout"],
+    );
+    assert_stdout("error: outer error\ncause: inner error\n", &output);
+    assert_stderr("", &output);
+}
+
+#[test]
+#[cfg(feature = "unicode-width")]
+fn status_wide_title_justified_by_display_width() {
+    let output = test_bin(STATUS_TEST_BIN, &["编译", "world", "green"]);
+    let expected = format!("{:>8}{} {}\n", "", "编译", "world");
+    assert_stderr(&expected, &output);
+}
+
+#[test]
+fn err_debug_pretty_output() {
+    let output = test_bin(ERR_DEBUG_TEST_BIN, &["connection refused", "cannot fetch"]);
+    let actual = String::from_utf8_lossy(&output.stderr);
+    assert!(actual.starts_with("Error {\n"), "{}", actual);
+    assert!(actual.contains("context: \"cannot fetch\""), "{}", actual);
+    assert!(
+        actual.contains("source: \"connection refused\""),
+        "{}",
+        actual
+    );
+}
+
+#[test]
+fn err_debug_backtrace() {
+    let output = Command::new(ERR_DEBUG_TEST_BIN)
+        .arg("connection refused")
+        .env("RUST_BACKTRACE", "1")
+        .output()
+        .unwrap();
+    let actual = String::from_utf8_lossy(&output.stderr);
+    assert!(actual.contains("\n\nStack backtrace:\n"), "{}", actual);
+}
+
+#[test]
+fn err_debug_no_backtrace() {
+    let output = Command::new(ERR_DEBUG_TEST_BIN)
+        .arg("connection refused")
+        .env("RUST_BACKTRACE", "0")
+        .env_remove("RUST_LIB_BACKTRACE")
+        .output()
+        .unwrap();
+    let actual = String::from_utf8_lossy(&output.stderr);
+    assert!(!actual.contains("Stack backtrace:"), "{}", actual);
+}
+
+mod format_status_string {
+    use narrate::{colored::Colorize, report, Color};
+
+    #[test]
+    fn no_color() {
+        let line = report::format_status_string("Compiled", "narrate", None);
+        assert_eq!("    Compiled narrate", line);
+    }
+
+    #[test]
+    fn long_title_not_padded() {
+        let line = report::format_status_string("Decompressing", "archive", None);
+        assert_eq!("Decompressing archive", line);
+    }
+
+    #[test]
+    fn color() {
+        let line = report::format_status_string("Compiled", "narrate", Some(Color::Green));
+        let expected = format!("    {} narrate", "Compiled".color(Color::Green).bold());
+        assert_eq!(expected, line);
+    }
+}
+
+mod write_to {
+    use narrate::{colored::Colorize, report, Color};
+
+    use super::*;
+
+    #[test]
+    fn status_no_color() {
+        let mut out = Vec::new();
+        report::status_to(&mut out, "Compiled", "narrate", None).unwrap();
+        assert_eq!("    Compiled narrate\n", String::from_utf8(out).unwrap());
+    }
+
+    #[test]
+    fn status_color() {
+        let mut out = Vec::new();
+        report::status_to(&mut out, "Compiled", "narrate", Some(Color::Green)).unwrap();
+        let expected = format!("    {} narrate\n", "Compiled".color(Color::Green).bold());
+        assert_eq!(expected, String::from_utf8(out).unwrap());
+    }
+
+    #[test]
+    fn err_with_help() {
+        let mut error = error_from!("invalid configuration");
+        error.add_help("try something else");
+        let mut out = Vec::new();
+        report::err_to(&mut out, &error, false).unwrap();
+        let expected = "error: invalid configuration\n\ntry something else\n";
+        assert_eq!(expected, String::from_utf8(out).unwrap());
+    }
+
+    #[test]
+    fn err_full_with_causes() {
+        let error = error_from!("inner error").wrap("outer error");
+        let mut out = Vec::new();
+        report::err_full_to(&mut out, &error, false).unwrap();
+        let expected = "error: outer error\ncause: inner error\n";
+        assert_eq!(expected, String::from_utf8(out).unwrap());
+    }
+}
+
+#[cfg(feature = "icons")]
+mod icons {
+    use super::*;
+
+    #[test]
+    fn err() {
+        let output = test_bin(ERR_TEST_BIN, &["message", "-H", "hint", "--icons"]);
+        assert_stderr("❌ error: message\n💡 hint: hint\n", &output);
+    }
+
+    #[test]
+    fn warn() {
+        let output = test_bin(WARN_TEST_BIN, &["--icons", "warn", "message"]);
+        assert_stderr("⚠️ warning: message\n", &output);
+    }
+
+    #[test]
+    fn ascii_fallback() {
+        let output = test_bin(ERR_TEST_BIN, &["message", "--icons", "--ascii"]);
+        assert_stderr("[x] error: message\n", &output);
+    }
+
+    #[test]
+    fn disabled_by_no_emoji() {
+        let output = Command::new(ERR_TEST_BIN)
+            .args(["message", "--icons"])
+            .env("NO_EMOJI", "1")
+            .output()
+            .expect("run test binary");
+        assert_stderr("error: message\n", &output);
+    }
+
+    #[test]
+    fn off_by_default() {
+        let output = test_bin(ERR_TEST_BIN, &["message"]);
+        assert_stderr("error: message\n", &output);
+    }
+}
+
+mod summary_counts {
+    use super::*;
+
+    #[test]
+    fn counts_each_kind() {
+        let args = ["err", "warn", "err", "note", "err"];
+        let output = test_bin(SUMMARY_TEST_BIN, &args);
+        let reports = "error: error\nwarning: warning\nerror: error\nnote: note\nerror: error\n";
+        let expected = format!("{}3 errors, 1 warning, 1 note\n", reports);
+        assert_stderr(&expected, &output);
+    }
+
+    #[test]
+    fn skips_kinds_not_reported() {
+        let output = test_bin(SUMMARY_TEST_BIN, &["warn", "warn"]);
+        let expected = "warning: warning\nwarning: warning\n2 warnings\n";
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn nothing_reported() {
+        let output = test_bin(SUMMARY_TEST_BIN, &[] as &[&str]);
+        assert_stderr("", &output);
+    }
+
+    #[test]
+    fn info_not_counted() {
+        let output = test_bin(SUMMARY_TEST_BIN, &["info", "warn"]);
+        let expected = "info: info\nwarning: warning\n1 warning\n";
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn reset() {
+        let output = test_bin(SUMMARY_TEST_BIN, &["err", "reset", "note"]);
+        let expected = "error: error\nnote: note\n1 note\n";
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn guard_prints_on_drop() {
+        let output = test_bin(SUMMARY_TEST_BIN, &["-g", "err", "note"]);
+        let expected = "error: error\nnote: note\n1 error, 1 note\n";
+        assert_stderr(expected, &output);
+    }
+}
+
+mod color_env {
+    use super::*;
+
+    const ANSI_ESCAPE: &str = "\x1b[";
+
+    #[test]
+    fn no_color_when_piped() {
+        let output = test_bin_with_env(ERR_TEST_BIN, &["message"], &[]);
+        assert_stderr("error: message\n", &output);
+    }
+
+    #[test]
+    fn clicolor_force_err() {
+        let output = test_bin_with_env(ERR_TEST_BIN, &["message"], &[("CLICOLOR_FORCE", "1")]);
+        assert_ansi(true, &output);
+    }
+
+    #[test]
+    fn clicolor_force_status() {
+        let args = ["hi", "world", "green"];
+        let output = test_bin_with_env(STATUS_TEST_BIN, &args, &[("CLICOLOR_FORCE", "1")]);
+        assert_ansi(true, &output);
+    }
+
+    #[test]
+    fn rgb_status_stripped_when_piped() {
+        let args = ["hi", "world", "#ff8800"];
+        let output = test_bin_with_env(STATUS_TEST_BIN, &args, &[]);
+        let expected = format!("{:>12} {}\n", "hi", "world");
+        assert_stderr(&expected, &output);
+    }
+
+    #[test]
+    fn rgb_status_truecolor() {
+        let args = ["hi", "world", "#ff8800"];
+        let output = test_bin_with_env(STATUS_TEST_BIN, &args, &[("CLICOLOR_FORCE", "1")]);
+        let actual = String::from_utf8_lossy(&output.stderr);
+        assert!(actual.contains("38;2;255;136;0"), "{:?}", actual);
+    }
+
+    #[test]
+    fn clicolor_force_zero_ignored() {
+        let output = test_bin_with_env(ERR_TEST_BIN, &["message"], &[("CLICOLOR_FORCE", "0")]);
+        assert_stderr("error: message\n", &output);
+    }
+
+    #[test]
+    fn no_color_err() {
+        let output = test_bin_with_env(ERR_TEST_BIN, &["message"], &[("NO_COLOR", "1")]);
+        assert_stderr("error: message\n", &output);
+    }
+
+    #[test]
+    fn no_color_overrides_clicolor_force() {
+        let env = [("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")];
+        let output = test_bin_with_env(ERR_TEST_BIN, &["message"], &env);
+        assert_stderr("error: message\n", &output);
+
+        let args = ["hi", "world", "green"];
+        let output = test_bin_with_env(STATUS_TEST_BIN, &args, &env);
+        assert_ansi(false, &output);
+    }
+
+    #[test]
+    fn clicolor_force_status_stdout() {
+        let args = ["-o", "hi", "world", "green"];
+        let output = test_bin_with_env(STATUS_TEST_BIN, &args, &[("CLICOLOR_FORCE", "1")]);
+        let actual = String::from_utf8_lossy(&output.stdout);
+        assert!(actual.contains(ANSI_ESCAPE), "{:?}", actual);
+    }
+
+    fn test_bin_with_env(binary: &str, args: &[&str], env: &[(&str, &str)]) -> Output {
+        Command::new(binary)
+            .args(args)
+            .env_remove("NO_COLOR")
+            .env_remove("CLICOLOR_FORCE")
+            .envs(env.iter().copied())
+            .output()
+            .unwrap_or_else(|err| {
+                panic!("Failed to execute binary for testing: {}. {}", binary, err)
+            })
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn color_on_tty() {
+        let output = test_bin_pty(ERR_TEST_BIN, &["message"], &[]);
+        assert!(output.starts_with(ANSI_ESCAPE), "{:?}", output);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn no_color_on_tty() {
+        let output = test_bin_pty(ERR_TEST_BIN, &["message"], &[("NO_COLOR", "1")]);
+        assert_eq!("error: message\n", output);
+    }
+
+    #[test]
+    fn color_choice_always_when_piped() {
+        let args = ["message", "--color=always"];
+        let output = test_bin_with_env(ERR_TEST_BIN, &args, &[]);
+        assert_ansi(true, &output);
+    }
+
+    #[test]
+    fn color_choice_always_status() {
+        let args = ["hi", "world", "green", "--color=always"];
+        let output = test_bin_with_env(STATUS_TEST_BIN, &args, &[("NO_COLOR", "1")]);
+        assert_ansi(true, &output);
+    }
+
+    #[test]
+    fn color_choice_never_overrides_clicolor_force() {
+        let args = ["message", "--color=never"];
+        let output = test_bin_with_env(ERR_TEST_BIN, &args, &[("CLICOLOR_FORCE", "1")]);
+        assert_stderr("error: message\n", &output);
+    }
+
+    #[test]
+    fn report_severity_colors() {
+        let cases = [
+            ("error", "\x1b[1;31m"),
+            ("warning", "\x1b[1;33m"),
+            ("note", "\x1b[1;34m"),
+            ("help", "\x1b[32m"),
+        ];
+        for (severity, ansi) in cases {
+            let args = [severity, "title", "message"];
+            let output = test_bin_with_env(SEVERITY_TEST_BIN, &args, &[("CLICOLOR_FORCE", "1")]);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            assert!(
+                stderr.starts_with(&format!("{}title", ansi)),
+                "{:?}",
+                stderr
+            );
+        }
+    }
+
+    #[test]
+    fn report_colored_overrides_color() {
+        let args = ["note", "tip", "message", "magenta"];
+        let output = test_bin_with_env(SEVERITY_TEST_BIN, &args, &[("CLICOLOR_FORCE", "1")]);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.starts_with("\x1b[1;35mtip"), "{:?}", stderr);
+    }
+
+    #[test]
+    fn theme_note_color() {
+        let args = ["note", "message", "--note-color=magenta"];
+        let output = test_bin_with_env(WARN_TEST_BIN, &args, &[("CLICOLOR_FORCE", "1")]);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.starts_with("\x1b[1;35mnote"), "{:?}", stderr);
+    }
+
+    #[test]
+    fn tee_copies_without_color() {
+        let path = std::env::temp_dir().join("narrate-tee-color.log");
+        let tee_arg = format!("--tee={}", path.display());
+        let args = ["message", "-h", "help message", &tee_arg];
+        let output = test_bin_with_env(ERR_TEST_BIN, &args, &[("CLICOLOR_FORCE", "1")]);
+        assert_ansi(true, &output);
+        let tee = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!("error: message\n\nhelp message\n", tee);
+    }
+
+    #[test]
+    fn tee_copies_plain_output() {
+        let path = std::env::temp_dir().join("narrate-tee-plain.log");
+        let tee_arg = format!("--tee={}", path.display());
+        let args = ["hi", "world", "green", &tee_arg, "--section"];
+        let output = test_bin_with_env(STATUS_TEST_BIN, &args, &[]);
+        let tee = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_stderr("            hi world\n", &output);
+        assert_eq!("            hi world\n", tee);
+    }
+
+    fn assert_ansi(expected: bool, output: &Output) {
+        let actual = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(
+            expected,
+            actual.contains(ANSI_ESCAPE),
+            "unexpected color output: {:?}",
+            actual
+        );
+    }
+}
+
+mod err_boxed {
+    use super::*;
+
+    #[test]
+    fn sized_to_message() {
+        let output = test_bin(ERR_TEST_BIN, &["message", "-b"]);
+        let expected = "┌────────────────┐\n│ error: message │\n└────────────────┘\n";
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn with_help() {
+        let output = test_bin(ERR_TEST_BIN, &["message", "-b", "-h", "help"]);
+        let expected = "┌────────────────┐\n│ error: message │\n└────────────────┘\n\nhelp\n";
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn ascii() {
+        let output = test_bin(ERR_TEST_BIN, &["message", "-b", "--ascii"]);
+        let expected = "******************\n* error: message *\n******************\n";
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn wrapped_to_terminal_width() {
+        let output = Command::new(ERR_TEST_BIN)
+            .args(["message", "-b"])
+            .env("COLUMNS", "12")
+            .output()
+            .expect("run test binary");
+        let expected = "┌──────────┐\n│ error: m │\n│ essage   │\n└──────────┘\n";
+        assert_stderr(expected, &output);
+    }
+}
+
+mod status_annotated {
+    use super::*;
+
+    fn run(columns: Option<&str>, extra: &[&str]) -> Output {
+        let mut command = Command::new(STATUS_TEST_BIN);
+        command
+            .args(["-a", "1.2s", "Compiling", "narrate", "green"])
+            .args(extra)
+            .env_remove("COLUMNS");
+        if let Some(columns) = columns {
+            command.env("COLUMNS", columns);
+        }
+        command.output().expect("run test binary")
+    }
+
+    #[test]
+    fn aligned_to_right_edge() {
+        let output = run(Some("30"), &[]);
+        assert_stderr("   Compiling narrate      1.2s\n", &output);
+    }
+
+    #[test]
+    fn unknown_width() {
+        let output = run(None, &[]);
+        assert_stderr("   Compiling narrate  1.2s\n", &output);
+    }
+
+    #[test]
+    fn too_narrow() {
+        let output = run(Some("20"), &[]);
+        assert_stderr("   Compiling narrate  1.2s\n", &output);
+    }
+
+    #[test]
+    fn section_indent_counted() {
+        let output = run(Some("30"), &["--section"]);
+        assert_stderr("     Compiling narrate    1.2s\n", &output);
+    }
+}
+
+mod banner {
+    use super::*;
+
+    fn run(columns: Option<&str>, args: &[&str]) -> Output {
+        let mut command = Command::new(BANNER_TEST_BIN);
+        command.args(args).env_remove("COLUMNS");
+        if let Some(columns) = columns {
+            command.env("COLUMNS", columns);
+        }
+        command.output().expect("run test binary")
+    }
+
+    #[test]
+    fn centered() {
+        let output = run(Some("20"), &["narrate", "v0.4.2"]);
+        assert_stderr("      narrate\n       v0.4.2\n", &output);
+    }
+
+    #[test]
+    fn unknown_width() {
+        let output = run(None, &["narrate", "v0.4.2"]);
+        assert_stderr("narrate\nv0.4.2\n", &output);
+    }
+
+    #[test]
+    fn wider_than_terminal() {
+        let output = run(Some("4"), &["narrate"]);
+        assert_stderr("narrate\n", &output);
+    }
+}
+
+mod section {
+    use super::*;
+
+    #[test]
+    fn status_indented() {
+        let output = test_bin(STATUS_TEST_BIN, &["--section", "hi", "world", "green"]);
+        let expected = format!("  {:>12} {}\n", "hi", "world");
+        assert_stderr(&expected, &output);
+    }
+
+    #[test]
+    fn nested_status_indented() {
+        let args = ["--section", "--section", "hi", "world", "green"];
+        let output = test_bin(STATUS_TEST_BIN, &args);
+        let expected = format!("    {:>12} {}\n", "hi", "world");
+        assert_stderr(&expected, &output);
+    }
+
+    #[test]
+    fn custom_indent_width() {
+        let args = ["--indent-width=3", "--section", "hi", "world", "green"];
+        let output = test_bin(STATUS_TEST_BIN, &args);
+        let expected = format!("   {:>12} {}\n", "hi", "world");
+        assert_stderr(&expected, &output);
+    }
+
+    #[test]
+    fn err_full_indented_except_blank_lines() {
+        let args = ["inner", "outer", "-h", "help", "--section"];
+        let expected = "  error: outer\n  cause: inner\n\n  help\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+}
+
+mod status_glyph {
+    use super::*;
+
+    #[test]
+    fn glyph_before_title() {
+        let output = test_bin(STATUS_TEST_BIN, &["-g", "✔", "Done", "all good", "green"]);
+        assert_stderr("✔ Done all good\n", &output);
+    }
+
+    #[test]
+    fn ascii_fallback() {
+        let args = ["--ascii", "-g", "✔", "Done", "all good", "green"];
+        let output = test_bin(STATUS_TEST_BIN, &args);
+        assert_stderr("  Done all good\n", &output);
+    }
+}
+
+mod justify {
+    use super::*;
+
+    #[test]
+    fn leading() {
+        let args = ["Compiled", "narrate", "green", "--justify=leading"];
+        let output = test_bin(STATUS_TEST_BIN, &args);
+        assert_stderr("Compiled     narrate\n", &output);
+    }
+
+    #[test]
+    fn trailing() {
+        let args = ["Compiled", "narrate", "green", "--justify=trailing"];
+        let output = test_bin(STATUS_TEST_BIN, &args);
+        assert_stderr("    Compiled narrate\n", &output);
+    }
+
+    #[test]
+    fn leading_long_title() {
+        let args = ["Decompressing", "archive", "green", "--justify=leading"];
+        let output = test_bin(STATUS_TEST_BIN, &args);
+        assert_stderr("Decompressing archive\n", &output);
+    }
+}
+
+mod status_count {
+    use super::*;
+
+    #[test]
+    fn plural() {
+        let output = test_bin(STATUS_TEST_BIN, &["-c", "Compiled", "42 file", "green"]);
+        let expected = format!("{:>12} {}\n", "Compiled", "42 files");
+        assert_stderr(&expected, &output);
+    }
+
+    #[test]
+    fn singular() {
+        let output = test_bin(STATUS_TEST_BIN, &["-c", "Compiled", "1 file", "green"]);
+        let expected = format!("{:>12} {}\n", "Compiled", "1 file");
+        assert_stderr(&expected, &output);
+    }
+
+    #[test]
+    fn zero_is_plural() {
+        let output = test_bin(STATUS_TEST_BIN, &["-c", "Compiled", "0 file", "green"]);
+        let expected = format!("{:>12} {}\n", "Compiled", "0 files");
+        assert_stderr(&expected, &output);
+    }
+
+    #[test]
+    fn aligned_to_count_width() {
+        let args = ["--count-width=4", "-c", "Compiled", "7 file", "green"];
+        let output = test_bin(STATUS_TEST_BIN, &args);
+        let expected = format!("{:>12} {}\n", "Compiled", "   7 files");
+        assert_stderr(&expected, &output);
+    }
+}
+
+mod report_severity {
+    use super::*;
+
+    #[test]
+    fn custom_title_for_each_severity() {
+        for severity in ["error", "warning", "note", "info", "help"] {
+            let output = test_bin(SEVERITY_TEST_BIN, &[severity, "custom", "message"]);
+            assert_stderr("custom: message\n", &output);
+        }
+    }
+
+    #[test]
+    fn report_colored_stripped_when_piped() {
+        let args = ["warning", "deprecated", "message", "magenta"];
+        let output = test_bin(SEVERITY_TEST_BIN, &args);
+        assert_stderr("deprecated: message\n", &output);
+    }
+}
+
+mod status_indent {
+    use super::*;
+
+    #[test]
+    fn indented_before_justified_title() {
+        let output = test_bin(STATUS_TEST_BIN, &["-i", "4", "Compiled", "parser", "green"]);
+        assert_stderr("        Compiled parser\n", &output);
+    }
+
+    #[test]
+    fn zero_indent_matches_status() {
+        let output = test_bin(STATUS_TEST_BIN, &["-i", "0", "Compiled", "parser", "green"]);
+        let expected = format!("{:>12} {}\n", "Compiled", "parser");
+        assert_stderr(&expected, &output);
+    }
+
+    #[test]
+    fn indented_leading_justify() {
+        let args = [
+            "--justify=leading",
+            "-i",
+            "2",
+            "Compiled",
+            "parser",
+            "green",
+        ];
+        let output = test_bin(STATUS_TEST_BIN, &args);
+        assert_stderr("  Compiled     parser\n", &output);
+    }
+}
+
+mod status_dedup {
+    use super::*;
+
+    #[test]
+    fn repeats_suppressed_when_piped() {
+        let args = ["-d", "Waiting", "for server,for server,for server", "cyan"];
+        let output = test_bin(STATUS_TEST_BIN, &args);
+        let expected = format!("{:>12} {}\n", "Waiting", "for server");
+        assert_stderr(&expected, &output);
+    }
+
+    #[test]
+    fn changed_message_printed_again() {
+        let args = ["-d", "Waiting", "a,a,b,b,a", "cyan"];
+        let output = test_bin(STATUS_TEST_BIN, &args);
+        let expected = format!("{0:>12} a\n{0:>12} b\n{0:>12} a\n", "Waiting");
+        assert_stderr(&expected, &output);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn repeat_keeps_section_indent_on_tty() {
+        let args = ["--section", "-d", "Waiting", "a,a", "cyan", "--color=never"];
+        let output = test_bin_pty(STATUS_TEST_BIN, &args, &[]);
+        let expected = format!("  {0:>12} a\n\x1b[1A\r\x1b[K  {0:>12} a (x2)\n", "Waiting");
+        assert_eq!(expected, output);
+    }
+}
+
+#[test]
+fn clear_line_no_output_when_piped() {
+    let output = test_bin(WARN_TEST_BIN, &["clear"]);
+    assert_stderr("", &output);
+}
+
+mod warn {
+    use super::*;
+
+    #[test]
+    fn warning_output_to_stderr() {
+        let output = test_bin(WARN_TEST_BIN, &["warn", "careful now"]);
+        assert_stderr("warning: careful now\n", &output);
+    }
+
+    #[test]
+    fn note_output_to_stderr() {
+        let output = test_bin(WARN_TEST_BIN, &["note", "for your information"]);
+        assert_stderr("note: for your information\n", &output);
+    }
+
+    #[test]
+    fn theme_warning_title() {
+        let args = ["warn", "careful now", "--warning-title=Warnung"];
+        let output = test_bin(WARN_TEST_BIN, &args);
+        assert_stderr("Warnung: careful now\n", &output);
+    }
+
+    #[test]
+    fn info_output_to_stderr() {
+        let output = test_bin(WARN_TEST_BIN, &["info", "using cache"]);
+        assert_stderr("info: using cache\n", &output);
+    }
+
+    #[test]
+    fn help_diagnostic_output_to_stderr() {
+        let output = test_bin(WARN_TEST_BIN, &["help", "try `--force`"]);
+        assert_stderr("help: try `--force`\n", &output);
+    }
+
+    #[test]
+    fn error_diagnostic_output_to_stderr() {
+        let output = test_bin(WARN_TEST_BIN, &["error", "cannot continue"]);
+        assert_stderr("error: cannot continue\n", &output);
+    }
+}
+
+mod github {
+    use super::*;
+
+    #[test]
+    fn error_annotation() {
+        let output = test_bin(GITHUB_TEST_BIN, &["err", "error message"]);
+        assert_stdout("::error::error message\n", &output);
+    }
+
+    #[test]
+    fn error_annotation_escapes_causes_and_help() {
+        let args = ["err", "inner 100%", "outer", "-h", "help message"];
+        let expected = "::error::outer%0Acause: inner 100%25%0A%0Ahelp message\n";
+        let output = test_bin(GITHUB_TEST_BIN, &args);
+        assert_stdout(expected, &output);
+    }
+
+    #[test]
+    fn warning_annotation() {
+        let output = test_bin(GITHUB_TEST_BIN, &["warn", "line 1\r\nline 2"]);
+        assert_stdout("::warning::line 1%0D%0Aline 2\n", &output);
+    }
+
+    #[test]
+    fn warning_annotation_redacted() {
+        let args = ["warn", "token 1234 expired", "--redact-digits"];
+        let output = test_bin(GITHUB_TEST_BIN, &args);
+        assert_stdout("::warning::token **** expired\n", &output);
+    }
+}
+
 mod anyhow_err {
     use anyhow::anyhow;
 
@@ -112,6 +947,40 @@ mod err {
             },
         ]);
     }
+
+    #[test]
+    fn hint_below_title() {
+        let args = ["error message", "-H", "a hint", "-h", "help message"];
+        let expected = "error: error message\nhint: a hint\n\nhelp message\n";
+        let output = test_bin(ERR_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn trailing_newline() {
+        let errors = [error_from!("inner error"), error_from!("outer error")];
+        let mut args = format_error_test_args(&errors);
+        args.push("--trailing-newline".into());
+        let expected = format_error_test_expected(&errors[1..]) + "\n";
+        let output = test_bin(ERR_TEST_BIN, &args);
+        assert_stderr(&expected, &output);
+    }
+
+    #[test]
+    fn full_help() {
+        let args = [
+            "inner",
+            "-h",
+            "first help",
+            "outer",
+            "-h",
+            "second help",
+            "-a",
+        ];
+        let expected = "error: outer\n\nfirst help\nsecond help\n";
+        let output = test_bin(ERR_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
 }
 
 mod err_full {
@@ -173,6 +1042,185 @@ mod err_full {
             },
         ]);
     }
+
+    #[test]
+    fn trailing_newline() {
+        let errors = [error_from!("inner error message"), {
+            let mut err = error_from!("outer error message");
+            err.add_help("help message");
+            err
+        }];
+        let mut args = format_error_test_args(&errors);
+        args.push("--trailing-newline".into());
+        let expected = format_error_test_expected(&errors) + "\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(&expected, &output);
+    }
+
+    #[test]
+    fn hint_before_causes() {
+        let args = ["inner error", "outer error", "-H", "a hint"];
+        let expected = "error: outer error\nhint: a hint\ncause: inner error\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn collapse_single_cause() {
+        let args = ["inner error", "outer error", "--collapse-single-cause"];
+        let expected = "error: outer error: inner error\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn collapse_single_cause_ignores_longer_chains() {
+        let errors = [
+            error_from!("root error"),
+            error_from!("inner error"),
+            error_from!("outer error"),
+        ];
+        let mut args = format_error_test_args(&errors);
+        args.push("--collapse-single-cause".into());
+        let expected = format_error_test_expected(&errors);
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(&expected, &output);
+    }
+
+    #[test]
+    fn warn_full() {
+        let args = ["inner error", "outer error", "-h", "help message", "-w"];
+        let expected = "warning: outer error\ncause: inner error\n\nhelp message\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn tty_help_skipped_when_piped() {
+        let args = ["inner error", "outer error", "-T", "help message"];
+        let expected = "error: outer error\ncause: inner error\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn default_help_when_none() {
+        let args = ["inner error", "outer error", "-D", "default help"];
+        let expected = "error: outer error\ncause: inner error\n\ndefault help\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn default_help_not_used_with_help() {
+        let args = ["inner error", "-h", "help message", "-D", "default help"];
+        let expected = "error: inner error\n\nhelp message\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn theme_error_title() {
+        let args = ["inner error", "outer error", "--error-title=Fehler"];
+        let expected = "Fehler: outer error\ncause: inner error\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn note_diagnostic_full() {
+        let args = ["inner error", "outer error", "-n"];
+        let expected = "note: outer error\ncause: inner error\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn duplicate_cause_hidden() {
+        let args = ["root error", "same error", "same error"];
+        let expected = "error: same error\ncause: root error\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn duplicate_single_cause_not_collapsed() {
+        let args = ["same error", "same error", "--collapse-single-cause"];
+        let expected = "error: same error\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn duplicate_cause_shown() {
+        let args = ["same error", "same error", "--show-duplicate-cause"];
+        let expected = "error: same error\ncause: same error\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn message_style_not_applied_without_tty() {
+        let args = ["inner error", "outer error", "--cause-color=bright black"];
+        let expected = "error: outer error\ncause: inner error\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn max_causes_truncates_chain() {
+        let args = ["root", "inner", "middle", "outer", "--max-causes=1"];
+        let expected = "error: outer\ncause: middle\n… (2 more causes)\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn max_causes_ascii_single_hidden_cause() {
+        let args = ["root", "inner", "outer", "--max-causes=1", "--ascii"];
+        let expected = "error: outer\ncause: inner\n... (1 more cause)\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn max_causes_above_chain_length() {
+        let args = ["inner", "outer", "--max-causes=5"];
+        let expected = "error: outer\ncause: inner\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn redactor_applied_to_messages_causes_and_help() {
+        let args = [
+            "token 1234",
+            "login 42 failed",
+            "-h",
+            "help 1",
+            "--redact-digits",
+        ];
+        let expected = "error: login ** failed\ncause: token ****\n\nhelp *\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
+
+    #[test]
+    fn redactor_applied_to_hint() {
+        let args = ["token 1", "top 2", "-H", "hint 3", "--redact-digits"];
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr("error: top *\nhint: hint *\ncause: token *\n", &output);
+    }
+
+    #[test]
+    fn custom_cause_label() {
+        let errors = [error_from!("inner error"), error_from!("outer error")];
+        let mut args = format_error_test_args(&errors);
+        args.push("--cause-label=caused by".into());
+        let expected = "error: outer error\ncaused by: inner error\n";
+        let output = test_bin(ERR_FULL_TEST_BIN, &args);
+        assert_stderr(expected, &output);
+    }
 }
 
 fn format_error_test_expected(errors: &[Error]) -> String {
@@ -223,6 +1271,7 @@ fn format_error_test_args(errors: &[Error]) -> Vec<String> {
 fn test_bin<S: AsRef<OsStr>>(binary: &str, args: &[S]) -> Output {
     Command::new(binary)
         .args(args)
+        .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .spawn()
         .unwrap_or_else(|err| panic!("Failed to execute binary for testing: {}. {}", binary, err))
@@ -235,6 +1284,26 @@ fn test_bin<S: AsRef<OsStr>>(binary: &str, args: &[S]) -> Output {
         })
 }
 
+/// Run `binary` with stdout and stderr attached to a pseudo-terminal, and
+/// return everything it printed.
+#[cfg(target_os = "linux")]
+fn test_bin_pty(binary: &str, args: &[&str], env: &[(&str, &str)]) -> String {
+    let command: Vec<_> = std::iter::once(binary)
+        .chain(args.iter().copied())
+        .map(|arg| format!("'{}'", arg.replace('\'', r"'\''")))
+        .collect();
+    let output = Command::new("script")
+        .args(["-qec", &command.join(" "), "/dev/null"])
+        .env_remove("NO_COLOR")
+        .env_remove("CLICOLOR_FORCE")
+        .envs(env.iter().copied())
+        .stdin(Stdio::null())
+        .output()
+        .unwrap_or_else(|err| panic!("Failed to run binary in a pty: {}. {}", binary, err));
+    // the terminal translates each newline into CRLF
+    String::from_utf8_lossy(&output.stdout).replace("\r\n", "\n")
+}
+
 fn assert_stderr(expected: &str, output: &Output) {
     let actual = String::from_utf8_lossy(&output.stderr);
     assert_eq!(
@@ -243,3 +1312,278 @@ fn assert_stderr(expected: &str, output: &Output) {
         expected, actual
     );
 }
+
+fn assert_stdout(expected: &str, output: &Output) {
+    let actual = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        expected, actual,
+        "\n# EXPECTED:\n{}# ACTUAL:\n{}",
+        expected, actual
+    );
+}
+
+mod render_err_to_fmt {
+    use narrate::report;
+
+    use super::*;
+
+    #[test]
+    fn matches_err_full_output() {
+        let errors = [error_from!("inner error message"), {
+            let mut err = error_from!("outer error message");
+            err.add_help("help message");
+            err
+        }];
+        let mut error = error_from!("inner error message").wrap("outer error message");
+        error.add_help("help message");
+
+        let mut out = String::new();
+        report::render_err_to_fmt(&error, &mut out).unwrap();
+        assert_eq!(format_error_test_expected(&errors), out);
+    }
+
+    #[test]
+    fn retry_after_before_help() {
+        let mut error = error_from!("inner")
+            .wrap("outer")
+            .with_retry_after(std::time::Duration::from_millis(1500));
+        error.add_help("help message");
+
+        let mut out = String::new();
+        report::render_err_to_fmt(&error, &mut out).unwrap();
+        let expected = "error: outer\ncause: inner\nretry after: 1.5s\n\nhelp message\n";
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn tagged_groups() {
+        let error = error_from!("root")
+            .wrap_tagged("network", "Network")
+            .wrap("retry")
+            .wrap_tagged("parse", "Parser")
+            .wrap("top");
+
+        let mut out = String::new();
+        report::render_err_to_fmt(&error, &mut out).unwrap();
+        let expected = "error: top\n[Parser]\ncause: parse\n[Network]\ncause: retry\ncause: network\ncause: root\n";
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn wrap_error_keeps_other_tags() {
+        let other = error_from!("parse")
+            .wrap_tagged("bad index", "Parser")
+            .wrap("top");
+        let error = error_from!("root")
+            .wrap_tagged("network", "Network")
+            .wrap_error(other);
+
+        let mut out = String::new();
+        report::render_err_to_fmt(&error, &mut out).unwrap();
+        let expected = "error: top\n[Parser]\ncause: bad index\n[Network]\ncause: parse\ncause: network\ncause: root\n";
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn untagged_causes_below_group() {
+        let error = error_from!("root")
+            .wrap("inner")
+            .wrap_tagged("outer", "App");
+
+        let mut out = String::new();
+        report::render_err_to_fmt(&error, &mut out).unwrap();
+        assert_eq!("error: outer\ncause: inner\ncause: root\n", out);
+    }
+
+    #[test]
+    fn appends_to_existing_content() {
+        let mut out = String::from("> ");
+        report::render_err_to_fmt(&error_from!("error message"), &mut out).unwrap();
+        assert_eq!("> error: error message\n", out);
+    }
+}
+
+mod err_json {
+    use narrate::{report, CliError};
+
+    use super::*;
+
+    fn json(error: &Error) -> String {
+        let mut out = Vec::new();
+        report::err_json(error, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn causes_help_and_exit_code() {
+        let mut error = Error::new(CliError::Config).wrap("cannot start");
+        error.add_help("first help");
+        error.add_help("second help");
+        let expected = concat!(
+            r#"{"message":"cannot start","causes":["invalid configuration"],"#,
+            r#""help":"first help\nsecond help","exit_code":78}"#,
+            "\n"
+        );
+        assert_eq!(expected, json(&error));
+    }
+
+    #[test]
+    fn no_causes_or_help() {
+        let expected = r#"{"message":"error","causes":[],"help":null,"exit_code":70}"#;
+        assert_eq!(format!("{}\n", expected), json(&error_from!("error")));
+    }
+
+    #[test]
+    fn strings_escaped() {
+        let error = error_from!("tab\there").wrap("\"quoted\" \\ path");
+        let expected =
+            r#"{"message":"\"quoted\" \\ path","causes":["tab\there"],"help":null,"exit_code":70}"#;
+        assert_eq!(format!("{}\n", expected), json(&error));
+    }
+}
+
+mod render_all {
+    use narrate::report;
+
+    use super::*;
+
+    #[test]
+    fn errors_warnings_and_summary() {
+        let errors = [
+            error_from!("inner").wrap("outer"),
+            error_from!("second error"),
+        ];
+        let warnings = [error_from!("careful")];
+
+        let expected = "ERRORS\n\n\
+            error: outer\ncause: inner\n\n\
+            error: second error\n\n\
+            WARNINGS\n\n\
+            warning: careful\n\n\
+            2 errors, 1 warning\n";
+        assert_eq!(expected, report::render_all(&errors, &warnings));
+    }
+
+    #[test]
+    fn empty_sections_omitted() {
+        let warnings = [error_from!("careful")];
+        let expected = "WARNINGS\n\nwarning: careful\n\n1 warning\n";
+        assert_eq!(expected, report::render_all(&[], &warnings));
+    }
+
+    #[test]
+    fn nothing_to_render() {
+        assert_eq!("", report::render_all(&[], &[]));
+    }
+}
+
+#[cfg(feature = "tracing")]
+mod tracing_span {
+    use narrate::report;
+    use tracing::{field::Empty, info_span};
+    use tracing_subscriber::{layer::SubscriberExt, Registry};
+
+    use super::*;
+
+    #[test]
+    fn prefixes_error_with_span_name() {
+        let error = error_from!("inner error").wrap("outer error");
+        let mut out = String::new();
+        tracing::subscriber::with_default(Registry::default(), || {
+            let _span = info_span!("fetch").entered();
+            report::render_err_to_fmt(&error, &mut out).unwrap();
+        });
+        assert_eq!("error: fetch: outer error\ncause: inner error\n", out);
+    }
+
+    #[test]
+    fn prefixes_error_with_span_fields() {
+        let error = error_from!("error message");
+        let mut out = String::new();
+        let subscriber = Registry::default().with(report::SpanFieldsLayer);
+        tracing::subscriber::with_default(subscriber, || {
+            let span = info_span!("fetch", url = "example.com", attempt = 2, status = Empty);
+            span.record("status", 503);
+            let _span = span.entered();
+            report::render_err_to_fmt(&error, &mut out).unwrap();
+        });
+        let expected = "error: fetch{url=\"example.com\" attempt=2 status=503}: error message\n";
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn unchanged_without_span() {
+        let error = error_from!("error message");
+        let mut out = String::new();
+        tracing::subscriber::with_default(Registry::default(), || {
+            report::render_err_to_fmt(&error, &mut out).unwrap();
+        });
+        assert_eq!("error: error message\n", out);
+    }
+}
+
+mod status_percent {
+    use super::*;
+
+    #[test]
+    fn only_finished_line_without_tty() {
+        let output = test_bin(PERCENT_TEST_BIN, &["Downloading", "200", "1", "84", "200"]);
+        assert_stderr(" Downloading [100%] 200/200\n", &output);
+    }
+
+    #[test]
+    fn unfinished_prints_nothing_without_tty() {
+        let output = test_bin(PERCENT_TEST_BIN, &["Downloading", "200", "1", "84"]);
+        assert_stderr("", &output);
+    }
+
+    #[test]
+    fn zero_total_is_finished() {
+        let output = test_bin(PERCENT_TEST_BIN, &["Checking", "0", "0"]);
+        assert_stderr("    Checking [100%] 0/0\n", &output);
+    }
+
+    #[test]
+    fn respects_section_indent() {
+        let output = test_bin(PERCENT_TEST_BIN, &["Checking", "3", "3", "--section"]);
+        assert_stderr("      Checking [100%] 3/3\n", &output);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn respects_section_indent_on_tty() {
+        let args = ["--section", "--color=never", "Checking", "4", "1", "4"];
+        let output = test_bin_pty(PERCENT_TEST_BIN, &args, &[]);
+        let expected = "\r\x1b[K      Checking [ 25%] 1/4\r\x1b[K      Checking [100%] 4/4\n";
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn eta_left_out_when_too_large() {
+        let total = u64::MAX.to_string();
+        let args = ["--color=never", "-e", "10", "Checking", &total, "1"];
+        let output = test_bin_pty(PERCENT_TEST_BIN, &args, &[]);
+        let expected = format!("\r\x1b[K    Checking [  0%] 1/{}", total);
+        assert_eq!(expected, output);
+    }
+}
+
+#[cfg(feature = "indicatif")]
+mod indicatif {
+    use super::*;
+
+    const INDICATIF_TEST_BIN: &str = env!("CARGO_BIN_EXE_report_indicatif_test");
+
+    #[test]
+    fn status_via() {
+        let output = test_bin(INDICATIF_TEST_BIN, &["status", "narrate"]);
+        assert_stderr("   Compiling narrate\n", &output);
+    }
+
+    #[test]
+    fn err_via() {
+        let output = test_bin(INDICATIF_TEST_BIN, &["err", "message"]);
+        assert_stderr("error: message\n", &output);
+    }
+}
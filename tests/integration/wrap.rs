@@ -1,6 +1,6 @@
 use std::{env, fs, path::PathBuf};
 
-use narrate::{CliError, ErrorWrap};
+use narrate::{wrap, CliError, ErrorWrap};
 
 use crate::util::{assert_function_error, error_stub_res, ok_res, ErrorStub, ExpectedErr};
 
@@ -12,6 +12,31 @@ fn context_str() {
     });
 }
 
+#[test]
+fn wrap_io_by_kind() {
+    let path = non_existent_dir().join("file.txt");
+    let res = fs::read(&path).wrap_io(|kind| match kind {
+        std::io::ErrorKind::NotFound => CliError::InputFileNotFound(path.clone()),
+        _ => CliError::ReadFile(path.clone()),
+    });
+    let error = res.unwrap_err();
+    assert_eq!(Some(&CliError::InputFileNotFound(path)), error.cli_error());
+    assert!(error.causes().next().unwrap().is::<std::io::Error>());
+}
+
+#[test]
+fn wrap_io_passes_through_other_errors() {
+    let error = error_stub_res().wrap_io(|_| CliError::OsErr).unwrap_err();
+    assert_eq!(ErrorStub.to_string(), error.to_string());
+    assert!(error.cli_error().is_none());
+}
+
+#[test]
+fn location_of_wrap() {
+    let (res, line) = (error_stub_res().wrap("context"), line!());
+    assert_eq!(line, res.unwrap_err().location().unwrap().line());
+}
+
 #[test]
 fn error_type() {
     assert_function_error(&ExpectedErr::new(CliError::Protocol), || {
@@ -44,6 +69,25 @@ fn wrap_with_is_lazy() {
     assert!(touched);
 }
 
+#[test]
+fn wrap_macro_formats_context() {
+    let context = String::from("context 42");
+    assert_function_error(&ExpectedErr::new(context), || {
+        wrap!(error_stub_res(), "context {}", 42)
+    });
+}
+
+#[test]
+fn wrap_macro_is_lazy() {
+    let mut touched = false;
+    let mut touch = || {
+        touched = true;
+        ""
+    };
+    let _ = wrap!(ok_res(), "{}", touch());
+    assert!(!touched);
+}
+
 #[test]
 fn add_help_no_error() {
     let help = "help msg";
@@ -97,3 +141,41 @@ fn non_existent_dir() -> PathBuf {
     assert!(!path.exists(), "dir should not exist: `{}`", path.display());
     path
 }
+
+#[test]
+fn option_some_unchanged() {
+    assert_eq!(1, Some(1).wrap("context").unwrap());
+    assert_eq!(1, Some(1).add_help("help").unwrap());
+}
+
+#[test]
+fn option_none_wrap() {
+    let error = None::<()>.wrap("not found").unwrap_err();
+    assert_eq!("not found", error.to_string());
+    assert_eq!(0, error.causes().len());
+}
+
+#[test]
+fn option_none_wrap_with() {
+    let name = "config";
+    let error = None::<()>
+        .wrap_with(|| format!("{} not found", name))
+        .unwrap_err();
+    assert_eq!("config not found", error.to_string());
+}
+
+#[test]
+fn option_none_add_help() {
+    let error = None::<()>.add_help("help").unwrap_err();
+    assert_eq!("missing value", error.to_string());
+    assert_eq!(Some("help"), error.help());
+
+    let error = None::<()>.add_help_with(|| "computed help").unwrap_err();
+    assert_eq!(Some("computed help"), error.help());
+}
+
+#[test]
+fn location_of_option_wrap() {
+    let (res, line) = (None::<()>.wrap("context"), line!());
+    assert_eq!(line, res.unwrap_err().location().unwrap().line());
+}